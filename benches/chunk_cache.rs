@@ -0,0 +1,96 @@
+use coyotes::{
+    Component, DefaultBuilder, Document, HtmlRules, RulesetImpl, TemplateBuilderImpl,
+    TemplateSteps, attr_val, text, tmpl, vlist,
+};
+use std::time::{Duration, Instant};
+
+/*
+    Renders a page of items with and without cached chunks.
+
+    cargo bench --bench chunk_cache
+*/
+
+const ITEMS: usize = 200;
+const RENDERS: usize = 500;
+
+// caches template steps but composes every chunk
+struct StepsBuilder {
+    builder: DefaultBuilder,
+}
+
+impl TemplateBuilderImpl for StepsBuilder {
+    fn build(&mut self, rules: &dyn RulesetImpl, template_str: &str) -> TemplateSteps {
+        self.builder.build(rules, template_str)
+    }
+}
+
+fn item(index: usize) -> Component {
+    tmpl(
+        "<li class=\"item\">
+	<a {}>
+		<span>item</span> {}
+	</a>
+	<p>a few words about an item</p>
+</li>",
+        [
+            attr_val("href", &format!("/items/{}", index)),
+            text(&index.to_string()),
+        ],
+    )
+}
+
+fn page(items: Component) -> Component {
+    tmpl(
+        "<!DOCTYPE html>
+<html>
+	<head>
+		<title>items</title>
+	</head>
+	<body>
+		<main>
+			<ul>
+				{}
+			</ul>
+		</main>
+	</body>
+</html>",
+        [items],
+    )
+}
+
+fn time_renders<B: TemplateBuilderImpl>(
+    document: &mut Document<HtmlRules, B>,
+    component: &Component,
+) -> (Duration, String) {
+    // the first render parses templates and fills caches
+    let mut results = document.render(component).unwrap();
+
+    let start = Instant::now();
+    for _ in 0..RENDERS {
+        results = document.render(component).unwrap();
+    }
+
+    (start.elapsed(), results)
+}
+
+fn main() {
+    let component = page(vlist(Vec::from_iter((0..ITEMS).map(item))));
+
+    let mut composed = Document::with_builder(
+        HtmlRules::new(),
+        StepsBuilder {
+            builder: DefaultBuilder::new(),
+        },
+    );
+    let (composed_time, composed_results) = time_renders(&mut composed, &component);
+
+    let mut cached = Document::with_rules(HtmlRules::new());
+    let (cached_time, cached_results) = time_renders(&mut cached, &component);
+
+    assert_eq!(composed_results, cached_results);
+
+    println!(
+        "{} renders of {} items\ncomposed chunks: {:?}\ncached chunks: {:?}",
+        RENDERS, ITEMS, composed_time, cached_time
+    );
+}
@@ -0,0 +1,90 @@
+use crate::components::{Component, attr_url};
+use crate::context::RenderContext;
+use std::collections::HashMap;
+
+/*
+    Components declare the stylesheets and scripts they depend on.
+
+    Assets are collected as portal entries keyed by kind and url. Stylesheets
+    are rendered at the "head" slot and scripts at the "body" slot
+    once per document in the order they were first declared.
+*/
+
+#[derive(Debug, Clone, Copy, Eq, Hash, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum AssetKind {
+    Stylesheet,
+    Script,
+    ModuleScript,
+}
+
+// fingerprinted urls of assets, provided by the render context
+#[derive(Debug, Clone, Default, Eq, PartialEq)]
+pub struct AssetManifest {
+    urls: HashMap<String, String>,
+}
+
+impl AssetManifest {
+    pub fn new() -> AssetManifest {
+        AssetManifest {
+            urls: HashMap::new(),
+        }
+    }
+
+    pub fn insert(&mut self, url: &str, fingerprinted_url: &str) {
+        self.urls
+            .insert(url.to_string(), fingerprinted_url.to_string());
+    }
+
+    pub fn with(mut self, url: &str, fingerprinted_url: &str) -> AssetManifest {
+        self.insert(url, fingerprinted_url);
+        self
+    }
+
+    pub fn get_url<'a>(&'a self, url: &'a str) -> &'a str {
+        match self.urls.get(url) {
+            Some(fingerprinted_url) => fingerprinted_url,
+            _ => url,
+        }
+    }
+}
+
+pub fn get_asset_slot(kind: &AssetKind) -> &'static str {
+    match kind {
+        AssetKind::Stylesheet => "head",
+        _ => "body",
+    }
+}
+
+// a script and a module script of the same url are separate entries
+pub fn get_asset_key(kind: &AssetKind, url: &str) -> String {
+    let prefix = match kind {
+        AssetKind::Stylesheet => "stylesheet",
+        AssetKind::Script => "script",
+        AssetKind::ModuleScript => "module_script",
+    };
+
+    format!("{}:{}", prefix, url)
+}
+
+pub fn get_asset_component(context: &RenderContext, kind: &AssetKind, url: &str) -> Component {
+    let asset_url = match context.get::<AssetManifest>() {
+        Some(manifest) => manifest.get_url(url),
+        _ => url,
+    };
+
+    match kind {
+        AssetKind::Stylesheet => Component::TmplString(
+            "<link rel=\"stylesheet\" {}>".to_string(),
+            Vec::from([attr_url("href", asset_url)]),
+        ),
+        AssetKind::Script => Component::TmplString(
+            "<script {}></script>".to_string(),
+            Vec::from([attr_url("src", asset_url)]),
+        ),
+        AssetKind::ModuleScript => Component::TmplString(
+            "<script type=\"module\" {}></script>".to_string(),
+            Vec::from([attr_url("src", asset_url)]),
+        ),
+    }
+}
@@ -0,0 +1,311 @@
+use crate::components::Component;
+use crate::context::{ErrorHook, LazyComponent, RenderContext};
+use crate::documents::{TemplateBuilderImpl, compose_string_with_context};
+use crate::errors::Errors;
+use crate::template_steps::RulesetImpl;
+use std::collections::HashMap;
+use std::fmt;
+use std::future::Future;
+use std::pin::Pin;
+use std::sync::{Arc, Mutex, MutexGuard};
+use std::task::{Context, Poll, Wake, Waker};
+
+/*
+    Async components hold a future that produces a component.
+
+    Async renders compose a document with compose_string and collect
+    the async components it could not render yet. Collected futures
+    are awaited and the document is composed again until every future
+    is resolved. Futures returned by lazy components or by other
+    futures are found the same way as any other component.
+
+    A future is polled by every render awaiting it and every render
+    is woken when it resolves, so renders can share component trees.
+
+    Only std::future is used so any executor can drive resolution.
+*/
+
+type ComponentFuture = Pin<Box<dyn Future<Output = Component> + Send>>;
+type ResolveFuture<'a> = Pin<Box<dyn Future<Output = ()> + Send + 'a>>;
+
+enum AsyncState {
+    Pending(ComponentFuture),
+    Ready(Arc<Component>),
+}
+
+// wakes every render awaiting the same future
+struct Wakers {
+    wakers: Mutex<Vec<Waker>>,
+}
+
+impl Wake for Wakers {
+    fn wake(self: Arc<Self>) {
+        let wakers = std::mem::take(&mut *lock(&self.wakers));
+        for waker in wakers {
+            waker.wake();
+        }
+    }
+}
+
+#[derive(Clone)]
+pub struct AsyncComponent {
+    state: Arc<Mutex<AsyncState>>,
+    wakers: Arc<Wakers>,
+}
+
+impl AsyncComponent {
+    pub fn new<F>(future: F) -> AsyncComponent
+    where
+        F: Future<Output = Component> + Send + 'static,
+    {
+        AsyncComponent {
+            state: Arc::new(Mutex::new(AsyncState::Pending(Box::pin(future)))),
+            wakers: Arc::new(Wakers {
+                wakers: Mutex::new(Vec::new()),
+            }),
+        }
+    }
+
+    // resolved component or None while the future is pending
+    pub fn get_component(&self) -> Option<Arc<Component>> {
+        match &*lock(&self.state) {
+            AsyncState::Ready(component) => Some(component.clone()),
+            _ => None,
+        }
+    }
+
+    // a future that panicked can't be polled again
+    pub(crate) fn has_failed(&self) -> bool {
+        self.state.is_poisoned()
+    }
+
+    // every render awaiting a component polls the same future
+    pub async fn resolve(&self) {
+        Resolve { async_cmpnt: self }.await
+    }
+}
+
+impl fmt::Debug for AsyncComponent {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "AsyncComponent")
+    }
+}
+
+impl PartialEq for AsyncComponent {
+    fn eq(&self, other: &AsyncComponent) -> bool {
+        Arc::ptr_eq(&self.state, &other.state)
+    }
+}
+
+impl Eq for AsyncComponent {}
+
+struct Resolve<'a> {
+    async_cmpnt: &'a AsyncComponent,
+}
+
+impl Future for Resolve<'_> {
+    type Output = ();
+
+    fn poll(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<()> {
+        let async_cmpnt = self.async_cmpnt;
+
+        // register before polling so a wake during the poll is not lost
+        {
+            let mut wakers = lock(&async_cmpnt.wakers.wakers);
+            if !wakers.iter().any(|waker| waker.will_wake(cx.waker())) {
+                wakers.push(cx.waker().clone());
+            }
+        }
+
+        // renders report a future that panicked as an error
+        let mut state = match async_cmpnt.state.lock() {
+            Ok(state) => state,
+            _ => return Poll::Ready(()),
+        };
+
+        let future = match &mut *state {
+            AsyncState::Pending(future) => future,
+            _ => return Poll::Ready(()),
+        };
+
+        let waker = Waker::from(async_cmpnt.wakers.clone());
+        let component = match future.as_mut().poll(&mut Context::from_waker(&waker)) {
+            Poll::Ready(component) => component,
+            _ => return Poll::Pending,
+        };
+
+        *state = AsyncState::Ready(Arc::new(component));
+        drop(state);
+        async_cmpnt.wakers.clone().wake();
+
+        Poll::Ready(())
+    }
+}
+
+// async components and lazy results collected by an async render
+#[derive(Default)]
+pub(crate) struct Unresolved {
+    async_cmpnts: Mutex<Vec<AsyncComponent>>,
+    lazy_cmpnts: Mutex<HashMap<usize, Vec<(RenderContext, Component)>>>,
+    errors: Mutex<Vec<(ErrorHook, Errors)>>,
+}
+
+impl Unresolved {
+    pub(crate) fn push_async_component(&self, async_cmpnt: &AsyncComponent) {
+        let mut async_cmpnts = lock(&self.async_cmpnts);
+        if !async_cmpnts.contains(async_cmpnt) {
+            async_cmpnts.push(async_cmpnt.clone());
+        }
+    }
+
+    pub(crate) fn get_async_components_len(&self) -> usize {
+        lock(&self.async_cmpnts).len()
+    }
+
+    // lazy components are evaluated once per context so futures
+    // they return are the same futures on every pass
+    pub(crate) fn get_lazy_component(
+        &self,
+        lazy_cmpnt: &LazyComponent,
+        context: &RenderContext,
+    ) -> Component {
+        let id = lazy_cmpnt.get_id();
+        if let Some(results) = lock(&self.lazy_cmpnts).get(&id) {
+            for (lazy_context, component) in results {
+                if lazy_context == context {
+                    return component.clone();
+                }
+            }
+        }
+
+        let component = lazy_cmpnt.get_component(context);
+        lock(&self.lazy_cmpnts)
+            .entry(id)
+            .or_default()
+            .push((context.clone(), component.clone()));
+
+        component
+    }
+
+    // errors are reported once the document is complete
+    pub(crate) fn push_error(&self, error_hook: &ErrorHook, error: &Errors) {
+        lock(&self.errors).push((error_hook.clone(), error.clone()));
+    }
+
+    fn take_async_components(&self) -> Vec<AsyncComponent> {
+        std::mem::take(&mut *lock(&self.async_cmpnts))
+    }
+
+    fn report_errors(&self) {
+        for (error_hook, error) in std::mem::take(&mut *lock(&self.errors)) {
+            error_hook.report(&error);
+        }
+    }
+
+    // cached contexts hold this collector, clearing them drops the cycle
+    fn clear(&self) {
+        lock(&self.async_cmpnts).clear();
+        lock(&self.lazy_cmpnts).clear();
+        lock(&self.errors).clear();
+    }
+}
+
+// await async components one at a time in the order they are found
+pub async fn compose_string_async<B: TemplateBuilderImpl, R: RulesetImpl>(
+    builder: &mut B,
+    rules: &R,
+    component: &Component,
+    context: &RenderContext,
+) -> Result<String, Errors> {
+    compose_passes(builder, rules, component, context, false).await
+}
+
+// await async components concurrently, one pass at a time
+pub async fn compose_string_async_concurrently<B: TemplateBuilderImpl, R: RulesetImpl>(
+    builder: &mut B,
+    rules: &R,
+    component: &Component,
+    context: &RenderContext,
+) -> Result<String, Errors> {
+    compose_passes(builder, rules, component, context, true).await
+}
+
+async fn compose_passes<B: TemplateBuilderImpl, R: RulesetImpl>(
+    builder: &mut B,
+    rules: &R,
+    component: &Component,
+    context: &RenderContext,
+    concurrently: bool,
+) -> Result<String, Errors> {
+    let context = context.extend(&RenderContext::new().with(Unresolved::default()));
+    let unresolved = match context.get::<Unresolved>() {
+        Some(unresolved) => unresolved,
+        _ => return compose_string_with_context(builder, rules, component, &context),
+    };
+
+    loop {
+        let results = compose_string_with_context(builder, rules, component, &context);
+
+        let async_cmpnts = unresolved.take_async_components();
+        if async_cmpnts.is_empty() {
+            unresolved.report_errors();
+            unresolved.clear();
+            return results;
+        }
+
+        // errors of an incomplete document are not reported
+        lock(&unresolved.errors).clear();
+
+        match concurrently {
+            true => {
+                let mut futures: Vec<Option<ResolveFuture>> = Vec::new();
+                for async_cmpnt in &async_cmpnts {
+                    futures.push(Some(Box::pin(async_cmpnt.resolve())));
+                }
+
+                JoinAll { futures }.await;
+            }
+            _ => {
+                for async_cmpnt in &async_cmpnts {
+                    async_cmpnt.resolve().await;
+                }
+            }
+        }
+    }
+}
+
+// a poisoned lock only guards plain data, recover it
+fn lock<T>(mutex: &Mutex<T>) -> MutexGuard<'_, T> {
+    match mutex.lock() {
+        Ok(guard) => guard,
+        Err(poisoned) => poisoned.into_inner(),
+    }
+}
+
+// polls every future until all futures are ready
+struct JoinAll<'a> {
+    futures: Vec<Option<ResolveFuture<'a>>>,
+}
+
+impl Future for JoinAll<'_> {
+    type Output = ();
+
+    fn poll(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<()> {
+        let join_all = self.get_mut();
+
+        let mut ready = true;
+        for slot in join_all.futures.iter_mut() {
+            if let Some(future) = slot {
+                match future.as_mut().poll(cx) {
+                    Poll::Ready(_) => *slot = None,
+                    Poll::Pending => ready = false,
+                }
+            }
+        }
+
+        match ready {
+            true => Poll::Ready(()),
+            false => Poll::Pending,
+        }
+    }
+}
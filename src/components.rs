@@ -1,15 +1,47 @@
+use crate::assets::AssetKind;
+#[cfg(feature = "async")]
+use crate::async_components::AsyncComponent;
+use crate::context::{LazyComponent, RenderContext};
+use crate::islands::ISLAND_MANIFEST_SLOT;
+use crate::scoped_styles::ScopedStyle;
+use std::fmt;
+use std::sync::Arc;
+
+// variants depend on features, match with a wildcard arm
 #[derive(Debug, Clone, Eq, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
+#[non_exhaustive]
 pub enum Component {
     Attr(String),
     AttrVal(String, String),
+    AttrUrl(String, String),
+    Classes(Vec<String>),
+    Styles(Vec<(String, String)>),
     List(Vec<Component>),
     Text(String),
+    Comment(String),
     Tmpl(Template, Vec<Component>),
     TmplString(String, Vec<Component>),
+    Shared(Arc<Component>),
+    Memo(String, Box<Component>),
+    Slot(String),
+    Portal(String, String, Box<Component>),
+    Asset(AssetKind, String),
+    Scoped(ScopedStyle, Box<Component>),
+    Island(String, String, Box<Component>),
+    Boundary(Box<Component>, Box<Component>),
+    #[cfg_attr(feature = "serde", serde(skip))]
+    WithContext(RenderContext, Box<Component>),
+    #[cfg_attr(feature = "serde", serde(skip))]
+    Lazy(LazyComponent),
+    #[cfg(feature = "async")]
+    #[cfg_attr(feature = "serde", serde(skip))]
+    Async(AsyncComponent),
     None,
 }
 
 #[derive(Debug, Clone, Eq, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
 pub struct Template {
     pub template_str: &'static str,
 }
@@ -27,6 +59,10 @@ pub fn text(txt: &str) -> Component {
     Component::Text(txt.to_string())
 }
 
+pub fn comment(text_str: &str) -> Component {
+    Component::Comment(text_str.to_string())
+}
+
 pub fn attr(attr_str: &str) -> Component {
     Component::Attr(attr_str.to_string())
 }
@@ -35,6 +71,53 @@ pub fn attr_val(attr_str: &str, value_txt: &str) -> Component {
     Component::AttrVal(attr_str.to_string(), value_txt.to_string())
 }
 
+// boolean attributes render only when true
+pub fn attr_bool(attr_str: &str, value: bool) -> Component {
+    match value {
+        true => Component::Attr(attr_str.to_string()),
+        _ => Component::None,
+    }
+}
+
+pub fn attr_num<T: AttrNumber>(attr_str: &str, value: T) -> Component {
+    match value.to_attr_value() {
+        Some(value_txt) => Component::AttrVal(attr_str.to_string(), value_txt),
+        _ => Component::None,
+    }
+}
+
+pub fn attr_opt<T: fmt::Display>(attr_str: &str, value: Option<T>) -> Component {
+    match value {
+        Some(value_txt) => Component::AttrVal(attr_str.to_string(), value_txt.to_string()),
+        _ => Component::None,
+    }
+}
+
+// urls are percent-encoded when rendered
+pub fn attr_url(attr_str: &str, url: &str) -> Component {
+    Component::AttrUrl(attr_str.to_string(), url.to_string())
+}
+
+pub fn classes<const N: usize>(class_names: [&str; N]) -> Component {
+    Component::Classes(class_names.iter().map(|name| name.to_string()).collect())
+}
+
+pub fn class_if(condition: bool, class_name: &str) -> Component {
+    match condition {
+        true => Component::Classes(Vec::from([class_name.to_string()])),
+        _ => Component::None,
+    }
+}
+
+pub fn styles<const N: usize>(declarations: [(&str, &str); N]) -> Component {
+    Component::Styles(
+        declarations
+            .iter()
+            .map(|(property, value)| (property.to_string(), value.to_string()))
+            .collect(),
+    )
+}
+
 pub fn list<const N: usize>(components: [Component; N]) -> Component {
     Component::List(Vec::from(components))
 }
@@ -42,3 +125,125 @@ pub fn list<const N: usize>(components: [Component; N]) -> Component {
 pub fn vlist(components: Vec<Component>) -> Component {
     Component::List(components)
 }
+
+// build expensive subtrees once and share them across threads and renders
+pub fn shared(component: &Arc<Component>) -> Component {
+    Component::Shared(component.clone())
+}
+
+// cache the rendered results of a component by key
+pub fn memo(key: &str, component: Component) -> Component {
+    Component::Memo(key.to_string(), Box::new(component))
+}
+
+// a named position where portal entries are rendered
+pub fn slot(name: &str) -> Component {
+    Component::Slot(name.to_string())
+}
+
+// render a component at the first slot with a matching name,
+// later entries replace earlier entries with the same key
+pub fn portal(slot: &str, key: &str, component: Component) -> Component {
+    Component::Portal(slot.to_string(), key.to_string(), Box::new(component))
+}
+
+// a portal entry for the "head" slot
+pub fn head(key: &str, component: Component) -> Component {
+    portal("head", key, component)
+}
+
+// a stylesheet rendered once at the "head" slot
+pub fn stylesheet(url: &str) -> Component {
+    Component::Asset(AssetKind::Stylesheet, url.to_string())
+}
+
+// a script rendered once at the "body" slot
+pub fn script(url: &str) -> Component {
+    Component::Asset(AssetKind::Script, url.to_string())
+}
+
+// a module script rendered once at the "body" slot
+pub fn module_script(url: &str) -> Component {
+    Component::Asset(AssetKind::ModuleScript, url.to_string())
+}
+
+// elements of the component receive the scope attribute of the style
+pub fn scoped(style: &ScopedStyle, component: Component) -> Component {
+    Component::Scoped(style.clone(), Box::new(component))
+}
+
+// an interactive component with json props hydrated by a client loader
+pub fn island(name: &str, props: &str, component: Component) -> Component {
+    Component::Island(name.to_string(), props.to_string(), Box::new(component))
+}
+
+// a json manifest of the islands of a document
+pub fn island_manifest() -> Component {
+    Component::Slot(ISLAND_MANIFEST_SLOT.to_string())
+}
+
+// render the fallback when the component fails to render
+pub fn boundary(component: Component, fallback: Component) -> Component {
+    Component::Boundary(Box::new(component), Box::new(fallback))
+}
+
+// provide context values to the lazy components of a subtree
+pub fn provide(context: RenderContext, component: Component) -> Component {
+    Component::WithContext(context, Box::new(component))
+}
+
+// a function component evaluated with the render context during rendering
+pub fn lazy<F>(callback: F) -> Component
+where
+    F: Fn(&RenderContext) -> Component + Send + Sync + 'static,
+{
+    Component::Lazy(LazyComponent::new(callback))
+}
+
+// a component produced by a future, resolved before rendering
+#[cfg(feature = "async")]
+pub fn future<F>(future: F) -> Component
+where
+    F: std::future::Future<Output = Component> + Send + 'static,
+{
+    Component::Async(AsyncComponent::new(future))
+}
+
+// Numbers format the same regardless of type.
+// Floats render without trailing zeros, -0 renders as 0,
+// and NaN or infinite values omit the attribute.
+pub trait AttrNumber {
+    fn to_attr_value(&self) -> Option<String>;
+}
+
+macro_rules! impl_attr_number_for_int {
+    ($($int:ty),*) => {
+        $(impl AttrNumber for $int {
+            fn to_attr_value(&self) -> Option<String> {
+                Some(self.to_string())
+            }
+        })*
+    };
+}
+
+macro_rules! impl_attr_number_for_float {
+    ($($float:ty),*) => {
+        $(impl AttrNumber for $float {
+            fn to_attr_value(&self) -> Option<String> {
+                if !self.is_finite() {
+                    return None;
+                }
+
+                match 0.0 == *self {
+                    true => Some("0".to_string()),
+                    _ => Some(self.to_string()),
+                }
+            }
+        })*
+    };
+}
+
+impl_attr_number_for_int!(
+    i8, i16, i32, i64, i128, isize, u8, u16, u32, u64, u128, usize
+);
+impl_attr_number_for_float!(f32, f64);
@@ -0,0 +1,148 @@
+use crate::components::Component;
+use crate::errors::Errors;
+use std::any::{Any, TypeId};
+use std::collections::HashMap;
+use std::fmt;
+use std::sync::Arc;
+
+/*
+    Values like a locale, a theme, or a CSP nonce are provided to
+    lazily evaluated components by type instead of being passed
+    through every intermediate function component.
+*/
+
+#[derive(Clone, Default)]
+pub struct RenderContext {
+    values: HashMap<TypeId, Arc<dyn Any + Send + Sync>>,
+}
+
+impl RenderContext {
+    pub fn new() -> RenderContext {
+        RenderContext {
+            values: HashMap::new(),
+        }
+    }
+
+    pub fn insert<T: Any + Send + Sync>(&mut self, value: T) {
+        self.values.insert(TypeId::of::<T>(), Arc::new(value));
+    }
+
+    pub fn with<T: Any + Send + Sync>(mut self, value: T) -> RenderContext {
+        self.insert(value);
+        self
+    }
+
+    pub fn get<T: Any + Send + Sync>(&self) -> Option<&T> {
+        match self.values.get(&TypeId::of::<T>()) {
+            Some(value) => value.downcast_ref::<T>(),
+            _ => None,
+        }
+    }
+
+    // values of the provided context replace values of the same type
+    pub fn extend(&self, context: &RenderContext) -> RenderContext {
+        let mut values = self.values.clone();
+        for (type_id, value) in &context.values {
+            values.insert(*type_id, value.clone());
+        }
+
+        RenderContext { values }
+    }
+}
+
+impl fmt::Debug for RenderContext {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "RenderContext({} values)", self.values.len())
+    }
+}
+
+// contexts are equal when they share the same values
+impl PartialEq for RenderContext {
+    fn eq(&self, other: &RenderContext) -> bool {
+        if self.values.len() != other.values.len() {
+            return false;
+        }
+
+        for (type_id, value) in &self.values {
+            match other.values.get(type_id) {
+                Some(other_value) => {
+                    if !Arc::ptr_eq(value, other_value) {
+                        return false;
+                    }
+                }
+                _ => return false,
+            }
+        }
+
+        true
+    }
+}
+
+impl Eq for RenderContext {}
+
+// a function component evaluated with the render context
+#[derive(Clone)]
+pub struct LazyComponent {
+    callback: Arc<dyn Fn(&RenderContext) -> Component + Send + Sync>,
+}
+
+impl LazyComponent {
+    pub fn new<F>(callback: F) -> LazyComponent
+    where
+        F: Fn(&RenderContext) -> Component + Send + Sync + 'static,
+    {
+        LazyComponent {
+            callback: Arc::new(callback),
+        }
+    }
+
+    pub fn get_component(&self, context: &RenderContext) -> Component {
+        (self.callback)(context)
+    }
+
+    #[cfg(feature = "async")]
+    pub(crate) fn get_id(&self) -> usize {
+        Arc::as_ptr(&self.callback) as *const () as usize
+    }
+}
+
+impl fmt::Debug for LazyComponent {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "LazyComponent")
+    }
+}
+
+impl PartialEq for LazyComponent {
+    fn eq(&self, other: &LazyComponent) -> bool {
+        Arc::ptr_eq(&self.callback, &other.callback)
+    }
+}
+
+impl Eq for LazyComponent {}
+
+// reports errors swallowed by boundaries
+#[derive(Clone)]
+pub struct ErrorHook {
+    callback: Arc<dyn Fn(&Errors) + Send + Sync>,
+}
+
+impl ErrorHook {
+    pub fn new<F>(callback: F) -> ErrorHook
+    where
+        F: Fn(&Errors) + Send + Sync + 'static,
+    {
+        ErrorHook {
+            callback: Arc::new(callback),
+        }
+    }
+
+    pub fn report(&self, error: &Errors) {
+        (self.callback)(error)
+    }
+}
+
+impl fmt::Debug for ErrorHook {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "ErrorHook")
+    }
+}
@@ -0,0 +1,120 @@
+/*
+    Components deserialize from the same shape they serialize to.
+
+    Template components reference a &'static str. A deserialized
+    template string is owned so Tmpl components deserialize into
+    TmplString components with the same template and injections.
+
+    A Template can only be deserialized from 'static data
+    like the results of include_str!.
+*/
+
+use crate::assets::AssetKind;
+use crate::components::{Component, Template};
+use crate::scoped_styles::ScopedStyle;
+use serde::{Deserialize, Deserializer};
+use std::sync::Arc;
+
+#[derive(Deserialize)]
+#[serde(rename = "Template")]
+struct TemplateDef {
+    template_str: String,
+}
+
+#[derive(Deserialize)]
+#[serde(rename = "Component")]
+enum ComponentDef {
+    Attr(String),
+    AttrVal(String, String),
+    AttrUrl(String, String),
+    Classes(Vec<String>),
+    Styles(Vec<(String, String)>),
+    List(Vec<ComponentDef>),
+    Text(String),
+    Comment(String),
+    Tmpl(TemplateDef, Vec<ComponentDef>),
+    TmplString(String, Vec<ComponentDef>),
+    Shared(Box<ComponentDef>),
+    Memo(String, Box<ComponentDef>),
+    Slot(String),
+    Portal(String, String, Box<ComponentDef>),
+    Asset(AssetKind, String),
+    Scoped(ScopedStyle, Box<ComponentDef>),
+    Island(String, String, Box<ComponentDef>),
+    Boundary(Box<ComponentDef>, Box<ComponentDef>),
+    None,
+}
+
+impl<'de> Deserialize<'de> for Component {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Component, D::Error> {
+        match ComponentDef::deserialize(deserializer) {
+            Ok(component_def) => Ok(get_component_from_def(component_def)),
+            Err(e) => Err(e),
+        }
+    }
+}
+
+impl<'de: 'static> Deserialize<'de> for Template {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Template, D::Error> {
+        #[derive(Deserialize)]
+        #[serde(rename = "Template")]
+        struct StaticTemplateDef {
+            template_str: &'static str,
+        }
+
+        match StaticTemplateDef::deserialize(deserializer) {
+            Ok(template_def) => Ok(Template {
+                template_str: template_def.template_str,
+            }),
+            Err(e) => Err(e),
+        }
+    }
+}
+
+fn get_component_from_def(component_def: ComponentDef) -> Component {
+    match component_def {
+        ComponentDef::Attr(attr) => Component::Attr(attr),
+        ComponentDef::AttrVal(attr, val) => Component::AttrVal(attr, val),
+        ComponentDef::AttrUrl(attr, url) => Component::AttrUrl(attr, url),
+        ComponentDef::Classes(classes) => Component::Classes(classes),
+        ComponentDef::Styles(styles) => Component::Styles(styles),
+        ComponentDef::List(list) => Component::List(get_components_from_defs(list)),
+        ComponentDef::Text(text) => Component::Text(text),
+        ComponentDef::Comment(comment) => Component::Comment(comment),
+        ComponentDef::Tmpl(template, injections) => {
+            Component::TmplString(template.template_str, get_components_from_defs(injections))
+        }
+        ComponentDef::TmplString(template_str, injections) => {
+            Component::TmplString(template_str, get_components_from_defs(injections))
+        }
+        ComponentDef::Shared(shared) => {
+            Component::Shared(Arc::new(get_component_from_def(*shared)))
+        }
+        ComponentDef::Memo(key, component) => {
+            Component::Memo(key, Box::new(get_component_from_def(*component)))
+        }
+        ComponentDef::Slot(name) => Component::Slot(name),
+        ComponentDef::Portal(slot, key, component) => {
+            Component::Portal(slot, key, Box::new(get_component_from_def(*component)))
+        }
+        ComponentDef::Asset(kind, url) => Component::Asset(kind, url),
+        ComponentDef::Scoped(style, component) => {
+            Component::Scoped(style, Box::new(get_component_from_def(*component)))
+        }
+        ComponentDef::Island(name, props, component) => {
+            Component::Island(name, props, Box::new(get_component_from_def(*component)))
+        }
+        ComponentDef::Boundary(component, fallback) => Component::Boundary(
+            Box::new(get_component_from_def(*component)),
+            Box::new(get_component_from_def(*fallback)),
+        ),
+        ComponentDef::None => Component::None,
+    }
+}
+
+fn get_components_from_defs(component_defs: Vec<ComponentDef>) -> Vec<Component> {
+    component_defs
+        .into_iter()
+        .map(get_component_from_def)
+        .collect()
+}
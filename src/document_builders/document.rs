@@ -0,0 +1,108 @@
+#[cfg(feature = "async")]
+use crate::async_components::{compose_string_async, compose_string_async_concurrently};
+use crate::components::Component;
+use crate::context::RenderContext;
+use crate::document_builders::template_builder::DefaultBuilder;
+use crate::documents::{
+    CompiledSteps, TemplateBuilderImpl, compose_string, compose_string_with_context,
+};
+use crate::errors::Errors;
+use crate::template_registry::TemplateRegistry;
+use crate::template_steps::{RulesetImpl, TemplateSteps};
+
+/*
+    A document builder renders components with a ruleset and a template builder.
+
+    Html, HtmlOnly, and Xml are documents with their own rulesets.
+    Custom rulesets and template builders render the same way:
+    Document::with_rules(MyRules::new()).render(&component)
+*/
+
+pub struct Document<R, B = DefaultBuilder> {
+    rules: R,
+    builder: B,
+}
+
+impl<R: RulesetImpl> Document<R> {
+    pub fn with_rules(rules: R) -> Document<R> {
+        Document {
+            rules,
+            builder: DefaultBuilder::new(),
+        }
+    }
+
+    // cache steps generated by compile_templates, steps must be
+    // compiled with the same ruleset as this document builder
+    pub fn prepare_steps(&mut self, template_steps: &[(&str, TemplateSteps)]) {
+        for (template_str, steps) in template_steps {
+            self.builder
+                .insert_steps(&self.rules, template_str, steps.clone());
+        }
+    }
+
+    // cache render functions generated by compile_templates, functions
+    // compiled with other ruleset decisions and templates without
+    // compiled steps are interpreted
+    pub fn prepare_compiled_steps(&mut self, compiled_steps: &[(&str, &str, CompiledSteps)]) {
+        for (template_str, rules_key, compiled) in compiled_steps {
+            self.builder
+                .insert_compiled_steps(&self.rules, template_str, rules_key, *compiled);
+        }
+    }
+}
+
+impl<R: RulesetImpl, B: TemplateBuilderImpl> Document<R, B> {
+    pub fn with_builder(rules: R, builder: B) -> Document<R, B> {
+        Document { rules, builder }
+    }
+
+    pub fn get_rules(&self) -> &R {
+        &self.rules
+    }
+
+    pub fn get_builder(&self) -> &B {
+        &self.builder
+    }
+
+    pub fn get_builder_mut(&mut self) -> &mut B {
+        &mut self.builder
+    }
+
+    // parse and cache the templates of a registry ahead of renders
+    pub fn prepare(&mut self, registry: &TemplateRegistry) {
+        for name in registry.get_names() {
+            if let Some(template_str) = registry.get_template_str(&name) {
+                self.builder.build(&self.rules, &template_str);
+            }
+        }
+    }
+
+    pub fn render(&mut self, component: &Component) -> Result<String, Errors> {
+        compose_string(&mut self.builder, &self.rules, component)
+    }
+
+    pub fn render_with_context(
+        &mut self,
+        component: &Component,
+        context: &RenderContext,
+    ) -> Result<String, Errors> {
+        compose_string_with_context(&mut self.builder, &self.rules, component, context)
+    }
+
+    // await async components one at a time while rendering
+    #[cfg(feature = "async")]
+    pub async fn render_async(&mut self, component: &Component) -> Result<String, Errors> {
+        let context = RenderContext::new();
+        compose_string_async(&mut self.builder, &self.rules, component, &context).await
+    }
+
+    // await async components concurrently, results keep document order
+    #[cfg(feature = "async")]
+    pub async fn render_async_concurrently(
+        &mut self,
+        component: &Component,
+    ) -> Result<String, Errors> {
+        let context = RenderContext::new();
+        compose_string_async_concurrently(&mut self.builder, &self.rules, component, &context).await
+    }
+}
@@ -1,3 +1,5 @@
+use crate::template_steps::DuplicateAttrPolicy;
+
 pub const MEGABYTE: usize = 1024 * 1024;
 pub const FALLBACK_CACHE_MEMORY_LIMIT: usize = 16 * MEGABYTE;
 pub const FALLBACK_DOCUMENT_MEMORY_LIMIT: usize = 32 * MEGABYTE;
@@ -6,8 +8,123 @@ pub const FALLBACK_DOCUMENT_MEMORY_LIMIT: usize = 32 * MEGABYTE;
 pub struct DocumentParams {
     pub cache_memory_limit: usize,
     pub document_memory_limit: usize,
+    pub duplicate_attr_policy: DuplicateAttrPolicy,
+    pub keep_comments: bool,
     pub respect_indentation: bool,
     pub embedded_content: String,
+    pub elements: ElementSets,
+}
+
+// params of the html document builder
+impl Default for DocumentParams {
+    fn default() -> DocumentParams {
+        DocumentParams {
+            cache_memory_limit: FALLBACK_CACHE_MEMORY_LIMIT,
+            document_memory_limit: FALLBACK_DOCUMENT_MEMORY_LIMIT,
+            duplicate_attr_policy: DuplicateAttrPolicy::Merge,
+            keep_comments: true,
+            respect_indentation: true,
+            embedded_content: String::from("html"),
+            elements: ElementSets::new(),
+        }
+    }
+}
+
+// tags added to or removed from a default set of elements
+#[derive(Clone, Debug, Default, Eq, PartialEq)]
+pub struct ElementSet {
+    pub include: Vec<String>,
+    pub exclude: Vec<String>,
+}
+
+impl ElementSet {
+    pub fn new() -> ElementSet {
+        ElementSet {
+            include: Vec::new(),
+            exclude: Vec::new(),
+        }
+    }
+
+    pub fn include(mut self, tag: &str) -> ElementSet {
+        self.include.push(tag.to_string());
+        self
+    }
+
+    pub fn exclude(mut self, tag: &str) -> ElementSet {
+        self.exclude.push(tag.to_string());
+        self
+    }
+
+    // excluded tags win over included tags
+    pub fn contains(&self, tag: &str, default: bool) -> bool {
+        if self.exclude.iter().any(|excluded| excluded == tag) {
+            return false;
+        }
+
+        default || self.include.iter().any(|included| included == tag)
+    }
+}
+
+#[derive(Clone, Debug, Default, Eq, PartialEq)]
+pub struct ElementSets {
+    pub embedded: ElementSet,
+    pub inline: ElementSet,
+    pub preformatted: ElementSet,
+    pub raw_text: ElementSet,
+    pub rcdata: ElementSet,
+    pub void: ElementSet,
+}
+
+impl ElementSets {
+    pub fn new() -> ElementSets {
+        ElementSets {
+            embedded: ElementSet::new(),
+            inline: ElementSet::new(),
+            preformatted: ElementSet::new(),
+            raw_text: ElementSet::new(),
+            rcdata: ElementSet::new(),
+            void: ElementSet::new(),
+        }
+    }
+}
+
+// close sequences like "</script" of default and included
+// raw text and rcdata elements
+pub fn get_close_sequences(elements: &ElementSets) -> Vec<String> {
+    let mut close_sequences = Vec::new();
+    let tags = ["script", "style", "textarea", "title"]
+        .into_iter()
+        .chain(elements.raw_text.include.iter().map(|tag| tag.as_str()))
+        .chain(elements.rcdata.include.iter().map(|tag| tag.as_str()));
+
+    for tag in tags {
+        let close_sequence = format!("</{}", tag);
+        if !close_sequences.contains(&close_sequence) {
+            close_sequences.push(close_sequence);
+        }
+    }
+
+    close_sequences
+}
+
+pub fn find_close_sequence<'a>(close_sequences: &'a [String], tag: &str) -> Option<&'a str> {
+    match close_sequences
+        .iter()
+        .find(|sequence| &sequence[2..] == tag)
+    {
+        Some(sequence) => Some(sequence),
+        _ => None,
+    }
+}
+
+pub fn find_alt_text_tag<'a>(close_sequences: &'a [String], sequence: &str) -> Option<&'a str> {
+    match close_sequences
+        .iter()
+        .find(|close_sequence| *close_sequence == sequence)
+    {
+        Some(close_sequence) => Some(&close_sequence[2..]),
+        _ => None,
+    }
 }
 
 // deprecated elements
@@ -69,6 +186,11 @@ pub fn is_embedded_el(tag: &str) -> bool {
     }
 }
 
+// raw text, content is text until the close sequence
+pub fn is_raw_text_el(tag: &str) -> bool {
+    matches!(tag, "script" | "style")
+}
+
 pub fn is_preformatted_text_el(tag: &str) -> bool {
     return "pre" == tag;
 }
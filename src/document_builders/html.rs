@@ -1,56 +1,45 @@
-use crate::components::Component;
+use crate::document_builders::document::Document;
 use crate::document_builders::flyweight as fw;
-use crate::document_builders::template_builder::Builder;
-use crate::documents::compose_string;
-use crate::errors::Errors;
-use crate::template_steps::RulesetImpl;
-
-pub struct Html {
-    rules: HtmlRules,
-    builder: Builder,
-}
+use crate::template_steps::{DuplicateAttrPolicy, RulesetImpl, is_rcdata_el};
+
+pub type Html = Document<HtmlRules>;
 
-impl Html {
+impl Document<HtmlRules> {
     pub fn new() -> Html {
-        Html {
-            rules: HtmlRules::new(),
-            builder: Builder::new(),
-        }
+        Document::with_rules(HtmlRules::new())
     }
 
     pub fn from(params: &fw::DocumentParams) -> Html {
-        Html {
-            rules: HtmlRules::from(params),
-            builder: Builder::new(),
-        }
-    }
-
-    pub fn render(&mut self, component: &Component) -> Result<String, Errors> {
-        compose_string(&mut self.builder, &self.rules, component)
+        Document::with_rules(HtmlRules::from(params))
     }
 }
 
 pub struct HtmlRules {
     params: fw::DocumentParams,
+    close_sequences: Vec<String>,
 }
 
 impl HtmlRules {
     pub fn new() -> HtmlRules {
-        let params = fw::DocumentParams {
-            cache_memory_limit: fw::FALLBACK_CACHE_MEMORY_LIMIT,
-            document_memory_limit: fw::FALLBACK_DOCUMENT_MEMORY_LIMIT,
-            embedded_content: String::from("html"),
-            respect_indentation: true,
-        };
+        let params = fw::DocumentParams::default();
 
-        HtmlRules { params }
+        HtmlRules::from(&params)
     }
 
     pub fn from(params: &fw::DocumentParams) -> HtmlRules {
         HtmlRules {
+            close_sequences: fw::get_close_sequences(&params.elements),
             params: params.clone(),
         }
     }
+
+    fn tag_is_alt_text_el(&self, tag: &str) -> bool {
+        self.params
+            .elements
+            .raw_text
+            .contains(tag, fw::is_raw_text_el(tag))
+            || self.tag_is_rcdata_el(tag)
+    }
 }
 
 impl RulesetImpl for HtmlRules {
@@ -62,10 +51,18 @@ impl RulesetImpl for HtmlRules {
         self.params.cache_memory_limit
     }
 
+    fn get_duplicate_attr_policy(&self) -> DuplicateAttrPolicy {
+        self.params.duplicate_attr_policy
+    }
+
     fn get_initial_embedded_content(&self) -> &str {
         &self.params.embedded_content
     }
 
+    fn keep_comments(&self) -> bool {
+        self.params.keep_comments
+    }
+
     fn tag_is_prefix_of_contentless_el(&self, tag: &str) -> Option<&str> {
         if tag.starts_with("!--") {
             return Some("!--");
@@ -89,17 +86,15 @@ impl RulesetImpl for HtmlRules {
     }
 
     fn get_close_sequence_from_alt_text_tag(&self, tag: &str) -> Option<&str> {
-        match tag {
-            "script" => Some("</script"),
-            "style" => Some("</style"),
+        match self.tag_is_alt_text_el(tag) {
+            true => fw::find_close_sequence(&self.close_sequences, tag),
             _ => None,
         }
     }
 
     fn get_alt_text_tag_from_close_sequence(&self, tag: &str) -> Option<&str> {
-        match tag {
-            "</script" => Some("script"),
-            "</style" => Some("style"),
+        match fw::find_alt_text_tag(&self.close_sequences, tag) {
+            Some(alt_text_tag) if self.tag_is_alt_text_el(alt_text_tag) => Some(alt_text_tag),
             _ => None,
         }
     }
@@ -113,18 +108,31 @@ impl RulesetImpl for HtmlRules {
     }
 
     fn tag_is_void_el(&self, tag: &str) -> bool {
-        fw::is_void_el(tag)
+        self.params.elements.void.contains(tag, fw::is_void_el(tag))
     }
 
     fn tag_is_embedded_content_el(&self, tag: &str) -> bool {
-        fw::is_embedded_el(tag)
+        self.params
+            .elements
+            .embedded
+            .contains(tag, fw::is_embedded_el(tag))
     }
 
     fn tag_is_preformatted_text_el(&self, tag: &str) -> bool {
-        fw::is_preformatted_text_el(tag)
+        self.params
+            .elements
+            .preformatted
+            .contains(tag, fw::is_preformatted_text_el(tag))
+    }
+
+    fn tag_is_rcdata_el(&self, tag: &str) -> bool {
+        self.params.elements.rcdata.contains(tag, is_rcdata_el(tag))
     }
 
     fn tag_is_inline_el(&self, tag: &str) -> bool {
-        fw::is_inline_el(tag)
+        self.params
+            .elements
+            .inline
+            .contains(tag, fw::is_inline_el(tag))
     }
 }
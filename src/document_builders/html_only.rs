@@ -1,56 +1,48 @@
-use crate::components::Component;
+use crate::document_builders::document::Document;
 use crate::document_builders::flyweight as fw;
-use crate::document_builders::template_builder::Builder;
-use crate::documents::compose_string;
-use crate::errors::Errors;
-use crate::template_steps::RulesetImpl;
-
-pub struct HtmlOnly {
-    rules: HtmlOnlyRules,
-    builder: Builder,
-}
+use crate::template_steps::{DuplicateAttrPolicy, RulesetImpl, is_rcdata_el};
+
+pub type HtmlOnly = Document<HtmlOnlyRules>;
 
-impl HtmlOnly {
+impl Document<HtmlOnlyRules> {
     pub fn new() -> HtmlOnly {
-        HtmlOnly {
-            rules: HtmlOnlyRules::new(),
-            builder: Builder::new(),
-        }
+        Document::with_rules(HtmlOnlyRules::new())
     }
 
     pub fn from(params: &fw::DocumentParams) -> HtmlOnly {
-        HtmlOnly {
-            rules: HtmlOnlyRules::from(params),
-            builder: Builder::new(),
-        }
-    }
-
-    pub fn render(&mut self, component: &Component) -> Result<String, Errors> {
-        compose_string(&mut self.builder, &self.rules, component)
+        Document::with_rules(HtmlOnlyRules::from(params))
     }
 }
 
 pub struct HtmlOnlyRules {
     params: fw::DocumentParams,
+    close_sequences: Vec<String>,
 }
 
 impl HtmlOnlyRules {
     pub fn new() -> HtmlOnlyRules {
         let params = fw::DocumentParams {
-            cache_memory_limit: fw::FALLBACK_CACHE_MEMORY_LIMIT,
-            document_memory_limit: fw::FALLBACK_DOCUMENT_MEMORY_LIMIT,
-            embedded_content: String::from("html"),
             respect_indentation: false,
+            ..fw::DocumentParams::default()
         };
 
-        HtmlOnlyRules { params }
+        HtmlOnlyRules::from(&params)
     }
 
     pub fn from(params: &fw::DocumentParams) -> HtmlOnlyRules {
         HtmlOnlyRules {
+            close_sequences: fw::get_close_sequences(&params.elements),
             params: params.clone(),
         }
     }
+
+    fn tag_is_alt_text_el(&self, tag: &str) -> bool {
+        self.params
+            .elements
+            .raw_text
+            .contains(tag, fw::is_raw_text_el(tag))
+            || self.tag_is_rcdata_el(tag)
+    }
 }
 
 impl RulesetImpl for HtmlOnlyRules {
@@ -62,10 +54,18 @@ impl RulesetImpl for HtmlOnlyRules {
         self.params.cache_memory_limit
     }
 
+    fn get_duplicate_attr_policy(&self) -> DuplicateAttrPolicy {
+        self.params.duplicate_attr_policy
+    }
+
     fn get_initial_embedded_content(&self) -> &str {
         &self.params.embedded_content
     }
 
+    fn keep_comments(&self) -> bool {
+        self.params.keep_comments
+    }
+
     fn get_close_sequence_from_contentless_tag(&self, tag: &str) -> Option<&str> {
         match tag {
             "!--" => Some("-->"),
@@ -89,17 +89,15 @@ impl RulesetImpl for HtmlOnlyRules {
     }
 
     fn get_close_sequence_from_alt_text_tag(&self, tag: &str) -> Option<&str> {
-        match tag {
-            "script" => Some("</script"),
-            "style" => Some("</style"),
+        match self.tag_is_alt_text_el(tag) {
+            true => fw::find_close_sequence(&self.close_sequences, tag),
             _ => None,
         }
     }
 
     fn get_alt_text_tag_from_close_sequence(&self, tag: &str) -> Option<&str> {
-        match tag {
-            "</script" => Some("script"),
-            "</style" => Some("style"),
+        match fw::find_alt_text_tag(&self.close_sequences, tag) {
+            Some(alt_text_tag) if self.tag_is_alt_text_el(alt_text_tag) => Some(alt_text_tag),
             _ => None,
         }
     }
@@ -118,18 +116,28 @@ impl RulesetImpl for HtmlOnlyRules {
     }
 
     fn tag_is_void_el(&self, tag: &str) -> bool {
-        fw::is_void_el(tag)
+        self.params.elements.void.contains(tag, fw::is_void_el(tag))
     }
 
     fn tag_is_embedded_content_el(&self, tag: &str) -> bool {
-        fw::is_embedded_el(tag)
+        self.params
+            .elements
+            .embedded
+            .contains(tag, fw::is_embedded_el(tag))
     }
 
     fn tag_is_preformatted_text_el(&self, tag: &str) -> bool {
-        fw::is_preformatted_text_el(tag)
+        self.params
+            .elements
+            .preformatted
+            .contains(tag, fw::is_preformatted_text_el(tag))
+    }
+
+    fn tag_is_rcdata_el(&self, tag: &str) -> bool {
+        self.params.elements.rcdata.contains(tag, is_rcdata_el(tag))
     }
 
-    fn tag_is_inline_el(&self, _tag: &str) -> bool {
-        true
+    fn tag_is_inline_el(&self, tag: &str) -> bool {
+        self.params.elements.inline.contains(tag, true)
     }
 }
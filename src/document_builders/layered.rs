@@ -0,0 +1,452 @@
+use crate::document_builders::flyweight as fw;
+use crate::template_steps::{DuplicateAttrPolicy, RulesetImpl};
+
+/*
+    Layered rules override the decisions of a base ruleset
+    and delegate everything else.
+
+    LayeredRules::new(HtmlRules::new())
+        .with_banned(ElementSet::new().include("iframe"))
+        .with_void(ElementSet::new().include("coyote-icon"))
+        .with_inline_rule(|tag, inline| inline || tag.starts_with("x-"))
+        .with_keep_comments(|_| false)
+
+    Element sets are applied first, then rules receive the decision
+    of the base ruleset and its sets and return the final decision.
+
+    Tags included in raw text or rcdata sets close on "</tag".
+    Other close sequences are set per tag.
+*/
+
+type Rule<T> = Box<dyn Fn(T) -> T + Send + Sync>;
+type TagRule = Box<dyn Fn(&str, bool) -> bool + Send + Sync>;
+
+pub struct LayeredRules<R> {
+    base: R,
+    banned: fw::ElementSet,
+    elements: fw::ElementSets,
+    close_sequences: Vec<String>,
+    tag_close_sequences: Vec<(String, String)>,
+    initial_embedded_content: Option<String>,
+    document_memory_limit: Option<Rule<usize>>,
+    cache_memory_limit: Option<Rule<usize>>,
+    duplicate_attr_policy: Option<Rule<DuplicateAttrPolicy>>,
+    keep_comments: Option<Rule<bool>>,
+    respect_indentation: Option<Rule<bool>>,
+    mergeable_attr: Option<TagRule>,
+    banned_el: Option<TagRule>,
+    comment_el: Option<TagRule>,
+    embedded_el: Option<TagRule>,
+    inline_el: Option<TagRule>,
+    preformatted_el: Option<TagRule>,
+    raw_text_el: Option<TagRule>,
+    rcdata_el: Option<TagRule>,
+    template_el: Option<TagRule>,
+    void_el: Option<TagRule>,
+}
+
+impl<R: RulesetImpl> LayeredRules<R> {
+    pub fn new(base: R) -> LayeredRules<R> {
+        LayeredRules {
+            base,
+            banned: fw::ElementSet::new(),
+            elements: fw::ElementSets::new(),
+            close_sequences: Vec::new(),
+            tag_close_sequences: Vec::new(),
+            initial_embedded_content: None,
+            document_memory_limit: None,
+            cache_memory_limit: None,
+            duplicate_attr_policy: None,
+            keep_comments: None,
+            respect_indentation: None,
+            mergeable_attr: None,
+            banned_el: None,
+            comment_el: None,
+            embedded_el: None,
+            inline_el: None,
+            preformatted_el: None,
+            raw_text_el: None,
+            rcdata_el: None,
+            template_el: None,
+            void_el: None,
+        }
+    }
+
+    pub fn get_base(&self) -> &R {
+        &self.base
+    }
+
+    pub fn with_banned(mut self, banned: fw::ElementSet) -> LayeredRules<R> {
+        self.banned = banned;
+        self
+    }
+
+    pub fn with_elements(mut self, elements: fw::ElementSets) -> LayeredRules<R> {
+        self.elements = elements;
+        self.close_sequences = fw::get_close_sequences(&self.elements);
+        self
+    }
+
+    pub fn with_embedded(mut self, embedded: fw::ElementSet) -> LayeredRules<R> {
+        self.elements.embedded = embedded;
+        self
+    }
+
+    pub fn with_inline(mut self, inline: fw::ElementSet) -> LayeredRules<R> {
+        self.elements.inline = inline;
+        self
+    }
+
+    pub fn with_preformatted(mut self, preformatted: fw::ElementSet) -> LayeredRules<R> {
+        self.elements.preformatted = preformatted;
+        self
+    }
+
+    pub fn with_raw_text(mut self, raw_text: fw::ElementSet) -> LayeredRules<R> {
+        self.elements.raw_text = raw_text;
+        self.close_sequences = fw::get_close_sequences(&self.elements);
+        self
+    }
+
+    pub fn with_rcdata(mut self, rcdata: fw::ElementSet) -> LayeredRules<R> {
+        self.elements.rcdata = rcdata;
+        self.close_sequences = fw::get_close_sequences(&self.elements);
+        self
+    }
+
+    pub fn with_void(mut self, void: fw::ElementSet) -> LayeredRules<R> {
+        self.elements.void = void;
+        self
+    }
+
+    // alt text elements close on this sequence instead of "</tag"
+    pub fn with_close_sequence(mut self, tag: &str, close_sequence: &str) -> LayeredRules<R> {
+        self.tag_close_sequences
+            .retain(|(prev_tag, _)| prev_tag != tag);
+        self.tag_close_sequences
+            .push((tag.to_string(), close_sequence.to_string()));
+        self
+    }
+
+    pub fn with_initial_embedded_content(mut self, embedded_content: &str) -> LayeredRules<R> {
+        self.initial_embedded_content = Some(embedded_content.to_string());
+        self
+    }
+
+    pub fn with_document_memory_limit<F>(mut self, rule: F) -> LayeredRules<R>
+    where
+        F: Fn(usize) -> usize + Send + Sync + 'static,
+    {
+        self.document_memory_limit = Some(Box::new(rule));
+        self
+    }
+
+    pub fn with_cache_memory_limit<F>(mut self, rule: F) -> LayeredRules<R>
+    where
+        F: Fn(usize) -> usize + Send + Sync + 'static,
+    {
+        self.cache_memory_limit = Some(Box::new(rule));
+        self
+    }
+
+    pub fn with_duplicate_attr_policy<F>(mut self, rule: F) -> LayeredRules<R>
+    where
+        F: Fn(DuplicateAttrPolicy) -> DuplicateAttrPolicy + Send + Sync + 'static,
+    {
+        self.duplicate_attr_policy = Some(Box::new(rule));
+        self
+    }
+
+    pub fn with_keep_comments<F>(mut self, rule: F) -> LayeredRules<R>
+    where
+        F: Fn(bool) -> bool + Send + Sync + 'static,
+    {
+        self.keep_comments = Some(Box::new(rule));
+        self
+    }
+
+    pub fn with_respect_indentation<F>(mut self, rule: F) -> LayeredRules<R>
+    where
+        F: Fn(bool) -> bool + Send + Sync + 'static,
+    {
+        self.respect_indentation = Some(Box::new(rule));
+        self
+    }
+
+    pub fn with_mergeable_attr_rule<F>(mut self, rule: F) -> LayeredRules<R>
+    where
+        F: Fn(&str, bool) -> bool + Send + Sync + 'static,
+    {
+        self.mergeable_attr = Some(Box::new(rule));
+        self
+    }
+
+    pub fn with_banned_rule<F>(mut self, rule: F) -> LayeredRules<R>
+    where
+        F: Fn(&str, bool) -> bool + Send + Sync + 'static,
+    {
+        self.banned_el = Some(Box::new(rule));
+        self
+    }
+
+    pub fn with_comment_rule<F>(mut self, rule: F) -> LayeredRules<R>
+    where
+        F: Fn(&str, bool) -> bool + Send + Sync + 'static,
+    {
+        self.comment_el = Some(Box::new(rule));
+        self
+    }
+
+    pub fn with_embedded_rule<F>(mut self, rule: F) -> LayeredRules<R>
+    where
+        F: Fn(&str, bool) -> bool + Send + Sync + 'static,
+    {
+        self.embedded_el = Some(Box::new(rule));
+        self
+    }
+
+    pub fn with_inline_rule<F>(mut self, rule: F) -> LayeredRules<R>
+    where
+        F: Fn(&str, bool) -> bool + Send + Sync + 'static,
+    {
+        self.inline_el = Some(Box::new(rule));
+        self
+    }
+
+    pub fn with_preformatted_rule<F>(mut self, rule: F) -> LayeredRules<R>
+    where
+        F: Fn(&str, bool) -> bool + Send + Sync + 'static,
+    {
+        self.preformatted_el = Some(Box::new(rule));
+        self
+    }
+
+    // raw text elements made by a rule need a close sequence
+    // from a raw text set or with_close_sequence
+    pub fn with_raw_text_rule<F>(mut self, rule: F) -> LayeredRules<R>
+    where
+        F: Fn(&str, bool) -> bool + Send + Sync + 'static,
+    {
+        self.raw_text_el = Some(Box::new(rule));
+        self
+    }
+
+    pub fn with_rcdata_rule<F>(mut self, rule: F) -> LayeredRules<R>
+    where
+        F: Fn(&str, bool) -> bool + Send + Sync + 'static,
+    {
+        self.rcdata_el = Some(Box::new(rule));
+        self
+    }
+
+    pub fn with_template_rule<F>(mut self, rule: F) -> LayeredRules<R>
+    where
+        F: Fn(&str, bool) -> bool + Send + Sync + 'static,
+    {
+        self.template_el = Some(Box::new(rule));
+        self
+    }
+
+    pub fn with_void_rule<F>(mut self, rule: F) -> LayeredRules<R>
+    where
+        F: Fn(&str, bool) -> bool + Send + Sync + 'static,
+    {
+        self.void_el = Some(Box::new(rule));
+        self
+    }
+
+    // rcdata elements are alt text elements that accept injections,
+    // tags with their own close sequence are raw text by default
+    fn tag_is_raw_text_el(&self, tag: &str) -> bool {
+        let base_raw_text = !self.base.tag_is_rcdata_el(tag)
+            && (self
+                .base
+                .get_close_sequence_from_alt_text_tag(tag)
+                .is_some()
+                || self.get_tag_close_sequence(tag).is_some());
+
+        let raw_text = self.elements.raw_text.contains(tag, base_raw_text);
+        apply_tag_rule(&self.raw_text_el, tag, raw_text)
+    }
+
+    fn get_tag_close_sequence(&self, tag: &str) -> Option<&str> {
+        match self
+            .tag_close_sequences
+            .iter()
+            .find(|(alt_text_tag, _)| alt_text_tag == tag)
+        {
+            Some((_, close_sequence)) => Some(close_sequence),
+            _ => None,
+        }
+    }
+
+    fn tag_is_alt_text_el(&self, tag: &str) -> bool {
+        self.tag_is_raw_text_el(tag) || self.tag_is_rcdata_el(tag)
+    }
+}
+
+impl<R: RulesetImpl> RulesetImpl for LayeredRules<R> {
+    fn get_document_memory_limit(&self) -> usize {
+        apply_rule(
+            &self.document_memory_limit,
+            self.base.get_document_memory_limit(),
+        )
+    }
+
+    fn get_cache_memory_limit(&self) -> usize {
+        apply_rule(&self.cache_memory_limit, self.base.get_cache_memory_limit())
+    }
+
+    fn get_duplicate_attr_policy(&self) -> DuplicateAttrPolicy {
+        apply_rule(
+            &self.duplicate_attr_policy,
+            self.base.get_duplicate_attr_policy(),
+        )
+    }
+
+    fn get_initial_embedded_content(&self) -> &str {
+        match &self.initial_embedded_content {
+            Some(embedded_content) => embedded_content,
+            _ => self.base.get_initial_embedded_content(),
+        }
+    }
+
+    fn keep_comments(&self) -> bool {
+        apply_rule(&self.keep_comments, self.base.keep_comments())
+    }
+
+    fn attr_is_mergeable(&self, attr: &str) -> bool {
+        apply_tag_rule(
+            &self.mergeable_attr,
+            attr,
+            self.base.attr_is_mergeable(attr),
+        )
+    }
+
+    fn attrs_are_case_sensitive(&self) -> bool {
+        self.base.attrs_are_case_sensitive()
+    }
+
+    fn tag_is_prefix_of_contentless_el(&self, tag: &str) -> Option<&str> {
+        self.base.tag_is_prefix_of_contentless_el(tag)
+    }
+
+    fn get_close_sequence_from_contentless_tag(&self, tag: &str) -> Option<&str> {
+        self.base.get_close_sequence_from_contentless_tag(tag)
+    }
+
+    fn get_contentless_tag_from_close_sequence(&self, tag: &str) -> Option<&str> {
+        self.base.get_contentless_tag_from_close_sequence(tag)
+    }
+
+    fn get_close_sequence_from_alt_text_tag(&self, tag: &str) -> Option<&str> {
+        if !self.tag_is_alt_text_el(tag) {
+            return None;
+        }
+
+        if let Some(close_sequence) = self.get_tag_close_sequence(tag) {
+            return Some(close_sequence);
+        }
+
+        match self.base.get_close_sequence_from_alt_text_tag(tag) {
+            Some(close_sequence) => Some(close_sequence),
+            _ => fw::find_close_sequence(&self.close_sequences, tag),
+        }
+    }
+
+    fn get_alt_text_tag_from_close_sequence(&self, tag: &str) -> Option<&str> {
+        let tag_close_sequence = self
+            .tag_close_sequences
+            .iter()
+            .find(|(_, close_sequence)| close_sequence == tag);
+
+        let alt_text_tag = match tag_close_sequence {
+            Some((alt_text_tag, _)) => Some(alt_text_tag.as_str()),
+            _ => match self.base.get_alt_text_tag_from_close_sequence(tag) {
+                Some(alt_text_tag) => Some(alt_text_tag),
+                _ => fw::find_alt_text_tag(&self.close_sequences, tag),
+            },
+        };
+
+        // tags with another close sequence do not close on this one
+        match alt_text_tag {
+            Some(alt_text_tag)
+                if self.get_close_sequence_from_alt_text_tag(alt_text_tag) == Some(tag) =>
+            {
+                Some(alt_text_tag)
+            }
+            _ => None,
+        }
+    }
+
+    fn respect_indentation(&self) -> bool {
+        apply_rule(&self.respect_indentation, self.base.respect_indentation())
+    }
+
+    fn tag_is_banned_el(&self, tag: &str) -> bool {
+        let banned = self.banned.contains(tag, self.base.tag_is_banned_el(tag));
+        apply_tag_rule(&self.banned_el, tag, banned)
+    }
+
+    fn tag_is_comment_el(&self, tag: &str) -> bool {
+        apply_tag_rule(&self.comment_el, tag, self.base.tag_is_comment_el(tag))
+    }
+
+    fn tag_is_void_el(&self, tag: &str) -> bool {
+        let void = self
+            .elements
+            .void
+            .contains(tag, self.base.tag_is_void_el(tag));
+        apply_tag_rule(&self.void_el, tag, void)
+    }
+
+    fn tag_is_embedded_content_el(&self, tag: &str) -> bool {
+        let embedded = self
+            .elements
+            .embedded
+            .contains(tag, self.base.tag_is_embedded_content_el(tag));
+        apply_tag_rule(&self.embedded_el, tag, embedded)
+    }
+
+    fn tag_is_preformatted_text_el(&self, tag: &str) -> bool {
+        let preformatted = self
+            .elements
+            .preformatted
+            .contains(tag, self.base.tag_is_preformatted_text_el(tag));
+        apply_tag_rule(&self.preformatted_el, tag, preformatted)
+    }
+
+    fn tag_is_rcdata_el(&self, tag: &str) -> bool {
+        let rcdata = self
+            .elements
+            .rcdata
+            .contains(tag, self.base.tag_is_rcdata_el(tag));
+        apply_tag_rule(&self.rcdata_el, tag, rcdata)
+    }
+
+    fn tag_is_template_el(&self, tag: &str) -> bool {
+        apply_tag_rule(&self.template_el, tag, self.base.tag_is_template_el(tag))
+    }
+
+    fn tag_is_inline_el(&self, tag: &str) -> bool {
+        let inline = self
+            .elements
+            .inline
+            .contains(tag, self.base.tag_is_inline_el(tag));
+        apply_tag_rule(&self.inline_el, tag, inline)
+    }
+}
+
+fn apply_rule<T>(rule: &Option<Rule<T>>, decision: T) -> T {
+    match rule {
+        Some(rule) => rule(decision),
+        _ => decision,
+    }
+}
+
+fn apply_tag_rule(rule: &Option<TagRule>, tag: &str, decision: bool) -> bool {
+    match rule {
+        Some(rule) => rule(tag, decision),
+        _ => decision,
+    }
+}
@@ -1,10 +1,16 @@
+mod document;
 mod flyweight;
 mod html;
 mod html_only;
+mod layered;
 mod template_builder;
 mod xml;
 
-pub use flyweight::DocumentParams;
+pub use document::Document;
+pub(crate) use flyweight::is_void_el;
+pub use flyweight::{DocumentParams, ElementSet, ElementSets};
 pub use html::{Html, HtmlRules};
 pub use html_only::{HtmlOnly, HtmlOnlyRules};
+pub use layered::LayeredRules;
+pub use template_builder::DefaultBuilder;
 pub use xml::{Xml, XmlRules};
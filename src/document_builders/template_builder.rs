@@ -1,28 +1,95 @@
-use crate::documents::TemplateBuilderImpl;
+use crate::documents::{ChunkResults, CompiledSteps, MemoResults, TagInfo, TemplateBuilderImpl};
+use crate::template_compiler::get_rules_key;
 use crate::template_steps::{RulesetImpl, TemplateSteps, compose};
 use std::collections::HashMap;
+use std::hash::{DefaultHasher, Hash, Hasher};
+use std::mem;
 
-pub struct Builder {
+// chunks are found by chunk index and a hash of their context,
+// the context is compared before cached results are used
+type ChunkCache = HashMap<(usize, u64), (Vec<TagInfo>, ChunkResults)>;
+
+pub struct DefaultBuilder {
     memory_footprint: usize,
     results_cache: HashMap<String, TemplateSteps>,
+    memo_cache: HashMap<(String, TagInfo), MemoResults>,
+    compiled_cache: HashMap<String, CompiledSteps>,
+    chunk_cache: HashMap<String, ChunkCache>,
 }
 
-impl Builder {
-    pub fn new() -> Builder {
-        Builder {
+impl DefaultBuilder {
+    pub fn new() -> DefaultBuilder {
+        DefaultBuilder {
             memory_footprint: 0,
             results_cache: HashMap::new(),
+            memo_cache: HashMap::new(),
+            compiled_cache: HashMap::new(),
+            chunk_cache: HashMap::new(),
         }
     }
-}
 
-impl TemplateBuilderImpl for Builder {
-    fn build(&mut self, rules: &dyn RulesetImpl, template_str: &str) -> TemplateSteps {
-        // obliterate cache if memory limit exceeded
+    // cache steps parsed ahead of time, like steps from a compiled template module
+    pub fn insert_steps(
+        &mut self,
+        rules: &dyn RulesetImpl,
+        template_str: &str,
+        steps: TemplateSteps,
+    ) {
+        self.check_memory_limit(rules);
+
+        self.memory_footprint += template_str.len();
+
+        self.results_cache.insert(template_str.to_string(), steps);
+    }
+
+    // compiled steps are functions and are never obliterated,
+    // steps compiled with other ruleset decisions are skipped
+    pub fn insert_compiled_steps(
+        &mut self,
+        rules: &dyn RulesetImpl,
+        template_str: &str,
+        rules_key: &str,
+        compiled_steps: CompiledSteps,
+    ) -> bool {
+        if get_rules_key(rules, template_str) != rules_key {
+            return false;
+        }
+
+        self.compiled_cache
+            .insert(template_str.to_string(), compiled_steps);
+
+        true
+    }
+
+    // obliterate cached steps, memos, and chunks
+    pub fn clear(&mut self) {
+        self.memory_footprint = 0;
+        self.results_cache = HashMap::new();
+        self.memo_cache = HashMap::new();
+        self.chunk_cache = HashMap::new();
+    }
+
+    pub fn get_memory_footprint(&self) -> usize {
+        self.memory_footprint
+    }
+
+    // obliterate caches if memory limit exceeded
+    fn check_memory_limit(&mut self, rules: &dyn RulesetImpl) {
         if rules.get_cache_memory_limit() < self.memory_footprint {
-            self.memory_footprint = 0;
-            self.results_cache = HashMap::new();
+            self.clear();
         }
+    }
+}
+
+impl Default for DefaultBuilder {
+    fn default() -> DefaultBuilder {
+        DefaultBuilder::new()
+    }
+}
+
+impl TemplateBuilderImpl for DefaultBuilder {
+    fn build(&mut self, rules: &dyn RulesetImpl, template_str: &str) -> TemplateSteps {
+        self.check_memory_limit(rules);
 
         if let Some(steps) = self.results_cache.get(template_str) {
             return steps.clone();
@@ -37,4 +104,96 @@ impl TemplateBuilderImpl for Builder {
 
         steps
     }
+
+    fn get_compiled_steps(&mut self, template_str: &str) -> Option<CompiledSteps> {
+        self.compiled_cache.get(template_str).copied()
+    }
+
+    fn get_chunk(
+        &mut self,
+        _rules: &dyn RulesetImpl,
+        template_str: &str,
+        index: usize,
+        tag_info_stack: &[TagInfo],
+    ) -> Option<ChunkResults> {
+        let chunks = self.chunk_cache.get(template_str)?;
+        let (context, chunk) = chunks.get(&(index, get_context_hash(tag_info_stack)))?;
+
+        match context == tag_info_stack {
+            true => Some(chunk.clone()),
+            _ => None,
+        }
+    }
+
+    fn set_chunk(
+        &mut self,
+        rules: &dyn RulesetImpl,
+        template_str: &str,
+        index: usize,
+        tag_info_stack: &[TagInfo],
+        chunk: ChunkResults,
+    ) {
+        self.check_memory_limit(rules);
+
+        if !self.chunk_cache.contains_key(template_str) {
+            self.memory_footprint += template_str.len();
+            self.chunk_cache
+                .insert(template_str.to_string(), HashMap::new());
+        }
+
+        self.memory_footprint += chunk.results.len()
+            + get_stack_footprint(tag_info_stack)
+            + get_stack_footprint(&chunk.tag_info_stack);
+
+        if let Some(chunks) = self.chunk_cache.get_mut(template_str) {
+            chunks.insert(
+                (index, get_context_hash(tag_info_stack)),
+                (tag_info_stack.to_vec(), chunk),
+            );
+        }
+    }
+
+    fn get_memo(
+        &mut self,
+        _rules: &dyn RulesetImpl,
+        key: &str,
+        tag_info: &TagInfo,
+    ) -> Option<MemoResults> {
+        // memos are scoped to the ruleset of this builder and the tag info
+        // they were rendered within (indentation, spacing, embedded content)
+        self.memo_cache
+            .get(&(key.to_string(), tag_info.clone()))
+            .cloned()
+    }
+
+    fn set_memo(
+        &mut self,
+        rules: &dyn RulesetImpl,
+        key: &str,
+        tag_info: &TagInfo,
+        memo: MemoResults,
+    ) {
+        self.check_memory_limit(rules);
+
+        self.memory_footprint += key.len() + memo.results.len();
+
+        self.memo_cache
+            .insert((key.to_string(), tag_info.clone()), memo);
+    }
+}
+
+fn get_context_hash(tag_info_stack: &[TagInfo]) -> u64 {
+    let mut hasher = DefaultHasher::new();
+    tag_info_stack.hash(&mut hasher);
+    hasher.finish()
+}
+
+fn get_stack_footprint(tag_info_stack: &[TagInfo]) -> usize {
+    let mut footprint = 0;
+    for tag_info in tag_info_stack {
+        footprint += mem::size_of::<TagInfo>() + tag_info.tag.len();
+        footprint += tag_info.embedded_content.len();
+    }
+
+    footprint
 }
@@ -1,56 +1,45 @@
-use crate::components::Component;
+use crate::document_builders::document::Document;
 use crate::document_builders::flyweight as fw;
-use crate::document_builders::template_builder::Builder;
-use crate::documents::compose_string;
-use crate::errors::Errors;
-use crate::template_steps::RulesetImpl;
-
-pub struct Xml {
-    rules: XmlRules,
-    builder: Builder,
-}
+use crate::template_steps::{DuplicateAttrPolicy, RulesetImpl};
+
+pub type Xml = Document<XmlRules>;
 
-impl Xml {
+impl Document<XmlRules> {
     pub fn new() -> Xml {
-        Xml {
-            rules: XmlRules::new(),
-            builder: Builder::new(),
-        }
+        Document::with_rules(XmlRules::new())
     }
 
     pub fn from(params: &fw::DocumentParams) -> Xml {
-        Xml {
-            rules: XmlRules::from(params.clone()),
-            builder: Builder::new(),
-        }
-    }
-
-    pub fn render(&mut self, component: &Component) -> Result<String, Errors> {
-        compose_string(&mut self.builder, &self.rules, component)
+        Document::with_rules(XmlRules::from(params.clone()))
     }
 }
 
 pub struct XmlRules {
     params: fw::DocumentParams,
+    close_sequences: Vec<String>,
 }
 
 impl XmlRules {
     pub fn new() -> XmlRules {
         let params = fw::DocumentParams {
-            cache_memory_limit: fw::FALLBACK_CACHE_MEMORY_LIMIT,
-            document_memory_limit: fw::FALLBACK_DOCUMENT_MEMORY_LIMIT,
             embedded_content: String::from("xml"),
             respect_indentation: false,
+            ..fw::DocumentParams::default()
         };
 
-        XmlRules { params }
+        XmlRules::from(params)
     }
 
     pub fn from(params: fw::DocumentParams) -> XmlRules {
         XmlRules {
-            params: params.clone(),
+            close_sequences: fw::get_close_sequences(&params.elements),
+            params,
         }
     }
+
+    fn tag_is_alt_text_el(&self, tag: &str) -> bool {
+        self.params.elements.raw_text.contains(tag, false) || self.tag_is_rcdata_el(tag)
+    }
 }
 
 impl RulesetImpl for XmlRules {
@@ -62,10 +51,22 @@ impl RulesetImpl for XmlRules {
         self.params.cache_memory_limit
     }
 
+    fn get_duplicate_attr_policy(&self) -> DuplicateAttrPolicy {
+        self.params.duplicate_attr_policy
+    }
+
     fn get_initial_embedded_content(&self) -> &str {
         &self.params.embedded_content
     }
 
+    fn keep_comments(&self) -> bool {
+        self.params.keep_comments
+    }
+
+    fn attrs_are_case_sensitive(&self) -> bool {
+        true
+    }
+
     fn get_close_sequence_from_contentless_tag(&self, tag: &str) -> Option<&str> {
         match tag {
             "?" => Some("?"),
@@ -100,12 +101,18 @@ impl RulesetImpl for XmlRules {
         return None;
     }
 
-    fn get_close_sequence_from_alt_text_tag(&self, _tag: &str) -> Option<&str> {
-        None
+    fn get_close_sequence_from_alt_text_tag(&self, tag: &str) -> Option<&str> {
+        match self.tag_is_alt_text_el(tag) {
+            true => fw::find_close_sequence(&self.close_sequences, tag),
+            _ => None,
+        }
     }
 
-    fn get_alt_text_tag_from_close_sequence(&self, _tag: &str) -> Option<&str> {
-        None
+    fn get_alt_text_tag_from_close_sequence(&self, tag: &str) -> Option<&str> {
+        match fw::find_alt_text_tag(&self.close_sequences, tag) {
+            Some(alt_text_tag) if self.tag_is_alt_text_el(alt_text_tag) => Some(alt_text_tag),
+            _ => None,
+        }
     }
 
     fn respect_indentation(&self) -> bool {
@@ -116,19 +123,30 @@ impl RulesetImpl for XmlRules {
         false
     }
 
-    fn tag_is_void_el(&self, _tag: &str) -> bool {
-        false
+    fn tag_is_void_el(&self, tag: &str) -> bool {
+        self.params.elements.void.contains(tag, false)
     }
 
-    fn tag_is_embedded_content_el(&self, _tag: &str) -> bool {
-        false
+    fn tag_is_embedded_content_el(&self, tag: &str) -> bool {
+        self.params.elements.embedded.contains(tag, false)
     }
 
     fn tag_is_preformatted_text_el(&self, tag: &str) -> bool {
-        "!CDATA[[" == tag
+        self.params
+            .elements
+            .preformatted
+            .contains(tag, "!CDATA[[" == tag)
+    }
+
+    fn tag_is_rcdata_el(&self, tag: &str) -> bool {
+        self.params.elements.rcdata.contains(tag, false)
     }
 
-    fn tag_is_inline_el(&self, _tag: &str) -> bool {
+    fn tag_is_template_el(&self, _tag: &str) -> bool {
         false
     }
+
+    fn tag_is_inline_el(&self, tag: &str) -> bool {
+        self.params.elements.inline.contains(tag, false)
+    }
 }
@@ -0,0 +1,278 @@
+use crate::documents::tag_info::{AttrInfo, TagInfo};
+use crate::errors::Errors;
+use crate::template_steps::{DuplicateAttrPolicy, RulesetImpl};
+
+pub fn push_attr_info(tag_info: &mut TagInfo, attr: &str, origin: usize, value_origin: usize) {
+    tag_info.attrs.push(AttrInfo {
+        attr: attr.to_string(),
+        origin,
+        value_origin,
+        target: value_origin,
+    });
+}
+
+pub fn close_attr_info(tag_info: &mut TagInfo, target: usize) {
+    if let Some(attr_info) = tag_info.attrs.last_mut() {
+        attr_info.target = target;
+    }
+}
+
+pub fn get_class_value(classes: &[String]) -> String {
+    let mut tokens: Vec<&str> = Vec::new();
+    for class in classes {
+        push_class_tokens(&mut tokens, class);
+    }
+
+    tokens.join(" ")
+}
+
+pub fn get_style_value(styles: &[(String, String)]) -> String {
+    let mut declarations: Vec<(&str, &str)> = Vec::new();
+    for (property, value) in styles {
+        push_style_declaration(&mut declarations, property.trim(), value.trim());
+    }
+
+    get_style_value_from_declarations(&declarations)
+}
+
+// https://url.spec.whatwg.org/#percent-encoded-bytes
+// reserved characters and existing percent-encodings are kept
+pub fn get_url_value(url: &str) -> String {
+    let bytes = url.as_bytes();
+
+    let mut encoded = String::new();
+    for (index, byte) in bytes.iter().enumerate() {
+        let glyph = *byte as char;
+        if glyph.is_ascii_alphanumeric() || is_url_safe_glyph(glyph) {
+            encoded.push(glyph);
+            continue;
+        }
+
+        if '%' == glyph && is_percent_encoded(&bytes[index + 1..]) {
+            encoded.push(glyph);
+            continue;
+        }
+
+        encoded.push_str(&format!("%{:02X}", byte));
+    }
+
+    encoded
+}
+
+// unreserved and reserved glyphs of RFC 3986
+fn is_url_safe_glyph(glyph: char) -> bool {
+    "-._~:/?#[]@!$&'()*+,;=".contains(glyph)
+}
+
+fn is_percent_encoded(bytes: &[u8]) -> bool {
+    match (bytes.first(), bytes.get(1)) {
+        (Some(first), Some(second)) => first.is_ascii_hexdigit() && second.is_ascii_hexdigit(),
+        _ => false,
+    }
+}
+
+// Attributes are tracked while an open tag is composed.
+// Once the tag closes, attributes declared more than once
+// are resolved by the duplicate attribute policy of the ruleset.
+pub fn resolve_attrs(
+    results: &mut String,
+    rules: &dyn RulesetImpl,
+    tag_info: &mut TagInfo,
+) -> Result<(), Errors> {
+    let attrs = std::mem::take(&mut tag_info.attrs);
+    if attrs.len() < 2 {
+        return Ok(());
+    }
+
+    let policy = rules.get_duplicate_attr_policy();
+
+    let mut edits: Vec<(usize, usize, String)> = Vec::new();
+    for (index, attr_info) in attrs.iter().enumerate() {
+        // only resolve an attribute from its first declaration
+        if attrs[..index]
+            .iter()
+            .any(|prev| is_same_attr(rules, &prev.attr, &attr_info.attr))
+        {
+            continue;
+        }
+
+        let duplicates: Vec<&AttrInfo> = attrs[index..]
+            .iter()
+            .filter(|next| is_same_attr(rules, &next.attr, &attr_info.attr))
+            .collect();
+
+        if duplicates.len() < 2 {
+            continue;
+        }
+
+        let attr = get_attr_name(rules, &attr_info.attr);
+
+        match policy {
+            DuplicateAttrPolicy::Error => {
+                return Err(Errors::DuplicateAttribute(
+                    attr_info.attr.clone(),
+                    tag_info.tag.clone(),
+                ));
+            }
+            DuplicateAttrPolicy::LastWins => {
+                for duplicate in &duplicates[..duplicates.len() - 1] {
+                    edits.push((duplicate.origin, duplicate.target, String::new()));
+                }
+            }
+            DuplicateAttrPolicy::Merge if rules.attr_is_mergeable(&attr) => {
+                let values: Vec<&str> = duplicates
+                    .iter()
+                    .map(|duplicate| get_value_from_attr_info(results, duplicate))
+                    .collect();
+
+                let merged = match attr.as_str() {
+                    "style" => merge_style_values(&values),
+                    _ => merge_class_values(&values),
+                };
+
+                let mut replacement = String::from("=\"");
+                replacement.push_str(&merged.replace("\"", "&quot;"));
+                replacement.push('"');
+                edits.push((attr_info.value_origin, attr_info.target, replacement));
+
+                for duplicate in &duplicates[1..] {
+                    edits.push((duplicate.origin, duplicate.target, String::new()));
+                }
+            }
+            _ => {
+                for duplicate in &duplicates[1..] {
+                    edits.push((duplicate.origin, duplicate.target, String::new()));
+                }
+            }
+        }
+    }
+
+    // edit from the end of the open tag so earlier locations remain valid
+    edits.sort_by_key(|edit| std::cmp::Reverse(edit.0));
+    for (origin, target, replacement) in edits {
+        results.replace_range(origin..target, &replacement);
+    }
+
+    Ok(())
+}
+
+// attributes declared since `attrs_len` that duplicate an earlier attribute
+// are errors before the tag closes when the ruleset forbids duplicates
+pub fn validate_attrs(
+    rules: &dyn RulesetImpl,
+    tag_info: &TagInfo,
+    attrs_len: usize,
+) -> Result<(), Errors> {
+    if DuplicateAttrPolicy::Error != rules.get_duplicate_attr_policy() {
+        return Ok(());
+    }
+
+    for (index, attr_info) in tag_info.attrs.iter().enumerate().skip(attrs_len) {
+        if tag_info.attrs[..index]
+            .iter()
+            .any(|prev| is_same_attr(rules, &prev.attr, &attr_info.attr))
+        {
+            return Err(Errors::DuplicateAttribute(
+                attr_info.attr.clone(),
+                tag_info.tag.clone(),
+            ));
+        }
+    }
+
+    Ok(())
+}
+
+fn is_same_attr(rules: &dyn RulesetImpl, attr: &str, other: &str) -> bool {
+    match rules.attrs_are_case_sensitive() {
+        true => attr == other,
+        _ => attr.eq_ignore_ascii_case(other),
+    }
+}
+
+fn get_attr_name(rules: &dyn RulesetImpl, attr: &str) -> String {
+    match rules.attrs_are_case_sensitive() {
+        true => attr.to_string(),
+        _ => attr.to_ascii_lowercase(),
+    }
+}
+
+fn get_value_from_attr_info<'a>(results: &'a str, attr_info: &AttrInfo) -> &'a str {
+    let value = &results[attr_info.value_origin..attr_info.target];
+    let value = value.strip_prefix('=').unwrap_or(value);
+
+    for quote in ['"', '\''] {
+        if let Some(unquoted) = value
+            .strip_prefix(quote)
+            .and_then(|value| value.strip_suffix(quote))
+        {
+            return unquoted;
+        }
+    }
+
+    value
+}
+
+fn merge_class_values(values: &[&str]) -> String {
+    let mut tokens: Vec<&str> = Vec::new();
+    for value in values {
+        push_class_tokens(&mut tokens, value);
+    }
+
+    tokens.join(" ")
+}
+
+fn push_class_tokens<'a>(tokens: &mut Vec<&'a str>, value: &'a str) {
+    for token in value.split_whitespace() {
+        if !tokens.contains(&token) {
+            tokens.push(token);
+        }
+    }
+}
+
+fn merge_style_values(values: &[&str]) -> String {
+    let mut declarations: Vec<(&str, &str)> = Vec::new();
+    for value in values {
+        for declaration in value.split(';') {
+            if let Some((property, value)) = declaration.split_once(':') {
+                push_style_declaration(&mut declarations, property.trim(), value.trim());
+            }
+        }
+    }
+
+    get_style_value_from_declarations(&declarations)
+}
+
+// later declarations of a property win, the first position is kept
+fn push_style_declaration<'a>(
+    declarations: &mut Vec<(&'a str, &'a str)>,
+    property: &'a str,
+    value: &'a str,
+) {
+    if property.is_empty() {
+        return;
+    }
+
+    for declaration in declarations.iter_mut() {
+        if declaration.0 == property {
+            declaration.1 = value;
+            return;
+        }
+    }
+
+    declarations.push((property, value));
+}
+
+fn get_style_value_from_declarations(declarations: &[(&str, &str)]) -> String {
+    let mut style = String::new();
+    for (property, value) in declarations {
+        if !style.is_empty() {
+            style.push_str("; ");
+        }
+
+        style.push_str(property);
+        style.push_str(": ");
+        style.push_str(value);
+    }
+
+    style
+}
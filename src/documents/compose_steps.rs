@@ -1,44 +1,216 @@
+use crate::documents::attributes::{close_attr_info, push_attr_info, resolve_attrs};
 use crate::documents::tag_info::{TagInfo, TextFormat};
 use crate::documents::text_components::{push_alt_text_component, push_multiline_attributes};
+use crate::errors::Errors;
 use crate::template_steps::{RulesetImpl, Step, StepKind, get_text_from_step};
 
+// composes the steps of a template chunk, the results of a compiled
+// template chunk must match the results of this function
 pub fn compose_steps(
     rules: &dyn RulesetImpl,
     results: &mut String,
     tag_info_stack: &mut Vec<TagInfo>,
     template_str: &str,
     steps: &Vec<Step>,
-) {
+) -> Result<(), Errors> {
+    let mut writer = StepWriter::new(rules, results, tag_info_stack);
+
     for step in steps {
-        match step.kind {
-            StepKind::Tag => push_element(results, tag_info_stack, rules, template_str, step),
-            StepKind::ElementClosed => close_element(results, tag_info_stack, rules),
-            StepKind::EmptyElementClosed => close_empty_element(results, tag_info_stack),
-            StepKind::TailTag => pop_element(results, tag_info_stack, rules, template_str, step),
-            StepKind::TailElementSpace => push_element_space(tag_info_stack, step),
-            StepKind::TailElementClosed => close_tail_tag(results, tag_info_stack),
-            StepKind::Text => push_text(results, tag_info_stack, template_str, step),
-            StepKind::TextAlt => push_alt_text(results, tag_info_stack, rules, template_str, step),
-            StepKind::TextLineSpace => push_text_space(results, tag_info_stack, template_str, step),
-            StepKind::TextSpace => push_text_space(results, tag_info_stack, template_str, step),
-            StepKind::Attr => push_attr(results, tag_info_stack, template_str, step),
-            StepKind::AttrValueSingleQuoted => {
-                push_attr_value_single_quoted(results, tag_info_stack, rules, template_str, step)
-            }
-            StepKind::AttrValueDoubleQuoted => {
-                push_attr_value_double_quoted(results, tag_info_stack, rules, template_str, step)
-            }
-            StepKind::AttrValueUnquoted => {
-                push_attr_value_unquoted(results, tag_info_stack, template_str, step)
+        compose_step(&mut writer, template_str, step)?;
+    }
+
+    Ok(())
+}
+
+pub(crate) fn compose_step(
+    writer: &mut StepWriter,
+    template_str: &str,
+    step: &Step,
+) -> Result<(), Errors> {
+    let text = get_text_from_step(template_str, step);
+    match step.kind {
+        StepKind::Tag => writer.push_element(text),
+        StepKind::ElementClosed => return writer.close_element(),
+        StepKind::EmptyElementClosed => return writer.close_empty_element(),
+        StepKind::TailTag => writer.pop_element(text),
+        StepKind::TailElementSpace => writer.push_element_space(),
+        StepKind::TailElementClosed => writer.close_tail_tag(),
+        StepKind::Text => writer.push_text(text),
+        StepKind::TextAlt => writer.push_alt_text(text),
+        StepKind::TextRcdata => writer.push_text(text),
+        StepKind::TextLineSpace => writer.push_text_line_space(text),
+        StepKind::TextSpace => writer.push_text_space(text),
+        StepKind::Attr => writer.push_attr(text),
+        StepKind::AttrValueSingleQuoted => writer.push_attr_value_single_quoted(text),
+        StepKind::AttrValueDoubleQuoted => writer.push_attr_value_double_quoted(text),
+        StepKind::AttrValueUnquoted => writer.push_attr_value_unquoted(text),
+        StepKind::ElementSpace => writer.push_element_space(),
+        StepKind::ElementLineSpace => writer.push_element_line_space(),
+        _ => {}
+    }
+
+    Ok(())
+}
+
+// compiled template chunks call a step writer directly
+// instead of interpreting template steps
+pub type CompiledSteps = fn(&mut StepWriter, usize) -> Result<(), Errors>;
+
+// the element a compiled chunk was pre-formatted within
+// and the indentation of that element in a document
+#[derive(Debug, Clone, Copy, Eq, PartialEq)]
+pub struct StaticFrame {
+    origin: usize,
+    indent: usize,
+}
+
+pub struct StepWriter<'a> {
+    rules: &'a dyn RulesetImpl,
+    results: &'a mut String,
+    tag_info_stack: &'a mut Vec<TagInfo>,
+}
+
+impl<'a> StepWriter<'a> {
+    pub(crate) fn new(
+        rules: &'a dyn RulesetImpl,
+        results: &'a mut String,
+        tag_info_stack: &'a mut Vec<TagInfo>,
+    ) -> StepWriter<'a> {
+        StepWriter {
+            rules,
+            results,
+            tag_info_stack,
+        }
+    }
+
+    pub fn push_element(&mut self, tag: &str) {
+        push_element(self.results, self.tag_info_stack, self.rules, tag)
+    }
+
+    pub fn close_element(&mut self) -> Result<(), Errors> {
+        close_element(self.results, self.tag_info_stack, self.rules)
+    }
+
+    pub fn close_empty_element(&mut self) -> Result<(), Errors> {
+        close_empty_element(self.results, self.tag_info_stack, self.rules)
+    }
+
+    pub fn pop_element(&mut self, tag: &str) {
+        pop_element(self.results, self.tag_info_stack, self.rules, tag)
+    }
+
+    pub fn close_tail_tag(&mut self) {
+        close_tail_tag(self.results, self.tag_info_stack)
+    }
+
+    pub fn push_text(&mut self, text: &str) {
+        push_text(self.results, self.tag_info_stack, text)
+    }
+
+    pub fn push_alt_text(&mut self, text: &str) {
+        push_alt_text(self.results, self.tag_info_stack, self.rules, text)
+    }
+
+    pub fn push_text_space(&mut self, text: &str) {
+        push_text_space(self.results, self.tag_info_stack, text, TextFormat::Space)
+    }
+
+    pub fn push_text_line_space(&mut self, text: &str) {
+        push_text_space(
+            self.results,
+            self.tag_info_stack,
+            text,
+            TextFormat::LineSpace,
+        )
+    }
+
+    pub fn push_element_space(&mut self) {
+        push_element_space(self.tag_info_stack, TextFormat::Space)
+    }
+
+    pub fn push_element_line_space(&mut self) {
+        push_element_space(self.tag_info_stack, TextFormat::LineSpace)
+    }
+
+    pub fn push_attr(&mut self, attr: &str) {
+        push_attr(self.results, self.tag_info_stack, attr)
+    }
+
+    pub fn push_attr_value_single_quoted(&mut self, text: &str) {
+        push_attr_value_single_quoted(self.results, self.tag_info_stack, self.rules, text)
+    }
+
+    pub fn push_attr_value_double_quoted(&mut self, text: &str) {
+        push_attr_value_double_quoted(self.results, self.tag_info_stack, self.rules, text)
+    }
+
+    pub fn push_attr_value_unquoted(&mut self, text: &str) {
+        push_attr_value_unquoted(self.results, self.tag_info_stack, text)
+    }
+
+    // Compiled chunks push results pre-formatted at build time when the
+    // element `depth` levels below the top of the stack is a plain element.
+    // Other elements are composed step by step.
+    pub fn get_static_frame(&self, depth: usize) -> Option<StaticFrame> {
+        let origin = match self.tag_info_stack.len().checked_sub(depth + 1) {
+            Some(origin) => origin,
+            _ => return None,
+        };
+
+        let tag_info = &self.tag_info_stack[origin];
+        let plain = !tag_info.banned_path
+            && !tag_info.preformatted_text_path
+            && tag_info.scope.is_none()
+            && tag_info.attrs.is_empty()
+            && tag_info.embedded_content == self.rules.get_initial_embedded_content();
+
+        match plain {
+            true => Some(StaticFrame {
+                origin,
+                indent: tag_info.indent_count,
+            }),
+            _ => None,
+        }
+    }
+
+    // pre-formatted results are indented by the frame between segments
+    pub fn push_static(&mut self, frame: &StaticFrame, segments: &[&str]) {
+        let indent = "\t".repeat(frame.indent);
+        for (index, segment) in segments.iter().enumerate() {
+            if 0 < index {
+                self.results.push_str(&indent);
             }
-            StepKind::ElementSpace => push_element_space(tag_info_stack, step),
-            StepKind::ElementLineSpace => push_element_space(tag_info_stack, step),
-            _ => {}
+            self.results.push_str(segment);
+        }
+    }
+
+    pub fn truncate_static(&mut self, frame: &StaticFrame, len: usize) {
+        self.tag_info_stack.truncate(frame.origin + len);
+    }
+
+    pub fn set_static_text_format(
+        &mut self,
+        frame: &StaticFrame,
+        index: usize,
+        text_format: TextFormat,
+    ) {
+        if let Some(tag_info) = self.tag_info_stack.get_mut(frame.origin + index) {
+            tag_info.text_format = text_format;
         }
     }
+
+    pub fn push_static_element(&mut self, tag: &str, text_format: TextFormat) {
+        let mut tag_info = match self.tag_info_stack.last() {
+            Some(prev_tag_info) => TagInfo::from(self.rules, prev_tag_info, tag),
+            _ => return,
+        };
+
+        tag_info.text_format = text_format;
+        self.tag_info_stack.push(tag_info);
+    }
 }
 
-fn push_text(results: &mut String, stack: &mut Vec<TagInfo>, template_str: &str, step: &Step) {
+fn push_text(results: &mut String, stack: &mut Vec<TagInfo>, text: &str) {
     let tag_info = match stack.last_mut() {
         Some(curr) => curr,
         _ => return,
@@ -48,7 +220,6 @@ fn push_text(results: &mut String, stack: &mut Vec<TagInfo>, template_str: &str,
         return;
     }
 
-    let text = get_text_from_step(template_str, step);
     if !tag_info.preformatted_text_path {
         push_formatted_space(results, &tag_info);
     }
@@ -62,8 +233,7 @@ fn push_alt_text(
     results: &mut String,
     stack: &mut Vec<TagInfo>,
     rules: &dyn RulesetImpl,
-    template_str: &str,
-    step: &Step,
+    text: &str,
 ) {
     let tag_info = match stack.last_mut() {
         Some(curr) => curr,
@@ -74,7 +244,6 @@ fn push_alt_text(
         return;
     }
 
-    let text = get_text_from_step(template_str, step);
     push_alt_text_component(results, rules, text, tag_info);
 
     tag_info.text_format = TextFormat::Text;
@@ -83,8 +252,8 @@ fn push_alt_text(
 fn push_text_space(
     results: &mut String,
     stack: &mut Vec<TagInfo>,
-    template_str: &str,
-    step: &Step,
+    text: &str,
+    text_format: TextFormat,
 ) {
     let tag_info = match stack.last_mut() {
         Some(curr) => curr,
@@ -96,7 +265,6 @@ fn push_text_space(
     }
 
     if tag_info.preformatted_text_path {
-        let text = get_text_from_step(template_str, step);
         results.push_str(text);
     }
 
@@ -105,14 +273,10 @@ fn push_text_space(
         return;
     }
 
-    tag_info.text_format = match step.kind {
-        StepKind::ElementLineSpace => TextFormat::LineSpace,
-        StepKind::TextLineSpace => TextFormat::LineSpace,
-        _ => TextFormat::Space,
-    }
+    tag_info.text_format = text_format
 }
 
-fn push_element_space(stack: &mut Vec<TagInfo>, step: &Step) {
+fn push_element_space(stack: &mut Vec<TagInfo>, text_format: TextFormat) {
     let tag_info = match stack.last_mut() {
         Some(curr) => curr,
         _ => return,
@@ -127,18 +291,14 @@ fn push_element_space(stack: &mut Vec<TagInfo>, step: &Step) {
         return;
     }
 
-    tag_info.text_format = match step.kind {
-        StepKind::ElementLineSpace => TextFormat::LineSpace,
-        _ => TextFormat::Space,
-    }
+    tag_info.text_format = text_format
 }
 
 fn push_element(
     results: &mut String,
     stack: &mut Vec<TagInfo>,
     rules: &dyn RulesetImpl,
-    template_str: &str,
-    step: &Step,
+    tag: &str,
 ) {
     let tag_info = match stack.last_mut() {
         Some(tag_info) => tag_info,
@@ -147,27 +307,58 @@ fn push_element(
         }
     };
 
-    let tag = get_text_from_step(template_str, step);
     let next_tag_info = TagInfo::from(rules, tag_info, tag);
 
+    // escapable raw text elements are spaced like other elements
+    let spaced = !next_tag_info.preformatted_text_path
+        || (rules.tag_is_rcdata_el(tag) && !tag_info.preformatted_text_path);
+
     if !next_tag_info.banned_path {
-        if !next_tag_info.preformatted_text_path {
+        if spaced {
             push_formatted_space(results, &tag_info);
         }
         results.push('<');
         results.push_str(tag);
+        push_scope_attr(results, &next_tag_info);
     }
 
     stack.push(next_tag_info);
 }
 
-fn close_element(results: &mut String, stack: &mut Vec<TagInfo>, rules: &dyn RulesetImpl) {
+// elements of scoped templates receive the scope attribute
+fn push_scope_attr(results: &mut String, tag_info: &TagInfo) {
+    let scope = match &tag_info.scope {
+        Some(scope) => scope,
+        _ => return,
+    };
+
+    // doctypes, comments, and processing instructions
+    if tag_info.tag.starts_with('!') || tag_info.tag.starts_with('?') {
+        return;
+    }
+
+    results.push(' ');
+    results.push_str(scope);
+
+    // xml attributes require values
+    if "xml" == tag_info.embedded_content {
+        results.push_str("=\"\"");
+    }
+}
+
+fn close_element(
+    results: &mut String,
+    stack: &mut Vec<TagInfo>,
+    rules: &dyn RulesetImpl,
+) -> Result<(), Errors> {
     let tag_info = match stack.last_mut() {
         Some(tag_info) => tag_info,
-        _ => return,
+        _ => return Ok(()),
     };
 
     if !tag_info.banned_path {
+        resolve_attrs(results, rules, tag_info)?;
+
         match tag_info.text_format {
             TextFormat::LineSpace => {
                 results.push('\n');
@@ -191,17 +382,19 @@ fn close_element(results: &mut String, stack: &mut Vec<TagInfo>, rules: &dyn Rul
 
     // for void elements
     if !tag_info.void_el {
-        return;
+        return Ok(());
     }
 
     if let Some(_) = stack.pop() {
         let prev_tag_info = match stack.last_mut() {
             Some(tag_info) => tag_info,
-            _ => return,
+            _ => return Ok(()),
         };
 
         prev_tag_info.text_format = TextFormat::Text;
     };
+
+    Ok(())
 }
 
 // Self-closing logic requires a sieve between HTML elements and non-html elements.
@@ -211,13 +404,19 @@ fn close_element(results: &mut String, stack: &mut Vec<TagInfo>, rules: &dyn Rul
 // - xml elements can self close
 // - html, svg, mathml elements that self-close like <tag/>
 //   expand into <tag></tag> and remain valid
-fn close_empty_element(results: &mut String, stack: &mut Vec<TagInfo>) {
-    let tag_info = match stack.pop() {
+fn close_empty_element(
+    results: &mut String,
+    stack: &mut Vec<TagInfo>,
+    rules: &dyn RulesetImpl,
+) -> Result<(), Errors> {
+    let mut tag_info = match stack.pop() {
         Some(curr) => curr,
-        _ => return,
+        _ => return Ok(()),
     };
 
     if !tag_info.banned_path {
+        resolve_attrs(results, rules, &mut tag_info)?;
+
         match tag_info.embedded_content.as_str() {
             "xml" => results.push_str("/>"),
             _ => match tag_info.void_el {
@@ -233,19 +432,15 @@ fn close_empty_element(results: &mut String, stack: &mut Vec<TagInfo>) {
 
     let prev_tag_info = match stack.last_mut() {
         Some(prev_tag_info) => prev_tag_info,
-        _ => return,
+        _ => return Ok(()),
     };
 
     prev_tag_info.text_format = TextFormat::Text;
+
+    Ok(())
 }
 
-fn pop_element(
-    results: &mut String,
-    stack: &mut Vec<TagInfo>,
-    rules: &dyn RulesetImpl,
-    template_str: &str,
-    step: &Step,
-) {
+fn pop_element(results: &mut String, stack: &mut Vec<TagInfo>, rules: &dyn RulesetImpl, tag: &str) {
     let tag_info = match stack.last() {
         Some(ti) => ti,
         _ => return,
@@ -259,7 +454,6 @@ fn pop_element(
         return;
     }
 
-    let tag = get_text_from_step(template_str, step);
     let mut closed_tag = tag;
     if let Some(close_tag) = rules.get_alt_text_tag_from_close_sequence(tag) {
         closed_tag = close_tag;
@@ -310,7 +504,7 @@ fn close_tail_tag(results: &mut String, stack: &mut Vec<TagInfo>) {
     prev_tag_info.text_format = TextFormat::Text;
 }
 
-fn push_attr(results: &mut String, stack: &mut Vec<TagInfo>, template_str: &str, step: &Step) {
+fn push_attr(results: &mut String, stack: &mut Vec<TagInfo>, attr: &str) {
     let tag_info = match stack.last_mut() {
         Some(curr) => curr,
         _ => return,
@@ -320,21 +514,18 @@ fn push_attr(results: &mut String, stack: &mut Vec<TagInfo>, template_str: &str,
         return;
     }
 
+    let origin = results.len();
     push_formatted_space(results, tag_info);
 
-    let attr = get_text_from_step(template_str, step);
-    results.push_str(attr.trim());
+    let attr = attr.trim();
+    results.push_str(attr);
+    push_attr_info(tag_info, attr, origin, results.len());
 
     tag_info.text_format = TextFormat::Text
 }
 
-fn push_attr_value_unquoted(
-    results: &mut String,
-    stack: &mut Vec<TagInfo>,
-    template_str: &str,
-    step: &Step,
-) {
-    let tag_info = match stack.last() {
+fn push_attr_value_unquoted(results: &mut String, stack: &mut Vec<TagInfo>, text: &str) {
+    let tag_info = match stack.last_mut() {
         Some(curr) => curr,
         _ => return,
     };
@@ -343,19 +534,18 @@ fn push_attr_value_unquoted(
         return;
     }
 
-    let text = get_text_from_step(template_str, step);
     results.push('=');
     results.push_str(text);
+    close_attr_info(tag_info, results.len());
 }
 
 fn push_attr_value_single_quoted(
     results: &mut String,
     stack: &mut Vec<TagInfo>,
     rules: &dyn RulesetImpl,
-    template_str: &str,
-    step: &Step,
+    text: &str,
 ) {
-    let tag_info = match stack.last() {
+    let tag_info = match stack.last_mut() {
         Some(curr) => curr,
         _ => return,
     };
@@ -364,20 +554,19 @@ fn push_attr_value_single_quoted(
         return;
     }
 
-    let text = get_text_from_step(template_str, step);
     results.push_str("='");
     push_multiline_attributes(results, rules, &text, tag_info);
     results.push('\'');
+    close_attr_info(tag_info, results.len());
 }
 
 fn push_attr_value_double_quoted(
     results: &mut String,
     stack: &mut Vec<TagInfo>,
     rules: &dyn RulesetImpl,
-    template_str: &str,
-    step: &Step,
+    text: &str,
 ) {
-    let tag_info = match stack.last() {
+    let tag_info = match stack.last_mut() {
         Some(curr) => curr,
         _ => return,
     };
@@ -386,10 +575,10 @@ fn push_attr_value_double_quoted(
         return;
     }
 
-    let text = get_text_from_step(template_str, step);
     results.push_str("=\"");
     push_multiline_attributes(results, rules, &text, tag_info);
     results.push('"');
+    close_attr_info(tag_info, results.len());
 }
 
 fn push_space_on_pop(results: &mut String, prev_tag_info: &TagInfo, tag_info: &TagInfo) {
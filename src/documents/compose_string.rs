@@ -1,15 +1,34 @@
+use crate::assets::{AssetKind, get_asset_component, get_asset_key, get_asset_slot};
+#[cfg(feature = "async")]
+use crate::async_components::{AsyncComponent, Unresolved};
 use crate::components::Component;
-use crate::documents::compose_steps::{compose_steps, push_formatted_space};
+use crate::context::{ErrorHook, LazyComponent, RenderContext};
+use crate::documents::attributes::{
+    close_attr_info, get_class_value, get_style_value, get_url_value, push_attr_info,
+    validate_attrs,
+};
+use crate::documents::compose_steps::{
+    CompiledSteps, StepWriter, compose_steps, push_formatted_space,
+};
+use crate::documents::slots::{
+    SlotEntry, SlotPosition, get_first_slot_positions, get_slot_entries,
+};
 use crate::documents::tag_info::{TagInfo, TextFormat};
-use crate::documents::template_builder::TemplateBuilderImpl;
+use crate::documents::template_builder::{ChunkResults, MemoResults, TemplateBuilderImpl};
 use crate::documents::text_components::{push_multiline_attributes, push_text_component};
 use crate::errors::Errors;
-use crate::template_steps::{RulesetImpl, StepKind, TemplateSteps};
+use crate::islands::{
+    ISLAND_MANIFEST_SLOT, get_island_component, get_island_manifest_entry, get_props_value,
+};
+use crate::scoped_styles::ScopedStyle;
+use crate::template_steps::{RulesetImpl, Step, StepKind, TemplateSteps};
 
 #[derive(Debug)]
 struct TemplateBit {
     pub inj_index: usize,
     pub stack_depth: usize,
+    pub compiled_steps: Option<CompiledSteps>,
+    pub injection_scope: Option<String>,
 }
 
 // Needed to track iteration across template steps and injections
@@ -19,16 +38,52 @@ enum StackBit<'a> {
     None,
 }
 
+// state of a document while it is composed
+struct ComposeState {
+    results: String,
+    tag_info_stack: Vec<TagInfo>,
+    context_stack: Vec<RenderContext>,
+    slots: Vec<SlotPosition>,
+    slot_entries: Vec<SlotEntry>,
+}
+
 pub fn compose_string(
     builder: &mut dyn TemplateBuilderImpl,
     rules: &dyn RulesetImpl,
     component: &Component,
 ) -> Result<String, Errors> {
-    let mut document_results = "".to_string();
+    compose_string_with_context(builder, rules, component, &RenderContext::new())
+}
+
+pub fn compose_string_with_context(
+    builder: &mut dyn TemplateBuilderImpl,
+    rules: &dyn RulesetImpl,
+    component: &Component,
+    context: &RenderContext,
+) -> Result<String, Errors> {
+    let mut state = ComposeState {
+        results: "".to_string(),
+        tag_info_stack: Vec::from([TagInfo::get_root(rules)]),
+        context_stack: Vec::from([context.clone()]),
+        slots: Vec::new(),
+        slot_entries: Vec::new(),
+    };
 
-    let mut tag_info_stack: Vec<TagInfo> = Vec::from([TagInfo::get_root(rules)]);
+    compose_component(builder, rules, &mut state, component)?;
+
+    compose_slots(builder, rules, &mut state)?;
+
+    Ok(state.results)
+}
+
+fn compose_component(
+    builder: &mut dyn TemplateBuilderImpl,
+    rules: &dyn RulesetImpl,
+    state: &mut ComposeState,
+    component: &Component,
+) -> Result<(), Errors> {
     let mut component_stack: Vec<StackBit> = Vec::from([get_bit_from_component_stack(
-        &mut tag_info_stack,
+        &mut state.tag_info_stack,
         builder,
         rules,
         component,
@@ -36,10 +91,10 @@ pub fn compose_string(
 
     while let Some(mut cmpnt_bit) = component_stack.pop() {
         // check document length
-        if rules.get_document_memory_limit() < document_results.len() {
+        if rules.get_document_memory_limit() < state.results.len() {
             return Err(Errors::DocumentMemoryLimitExceeded(
                 rules.get_document_memory_limit(),
-                document_results.len(),
+                state.results.len(),
             ));
         }
 
@@ -49,15 +104,71 @@ pub fn compose_string(
                 Component::Text(text) => {
                     let escaped_text = remove_template_glyphs(text);
                     push_text_component_injection(
-                        &mut document_results,
-                        &mut tag_info_stack,
+                        &mut state.results,
+                        &mut state.tag_info_stack,
                         &escaped_text,
                     );
                 }
+                // comments are dropped unless the ruleset keeps them
+                Component::Comment(comment) if rules.keep_comments() => {
+                    let escaped_comment = escape_comment_glyphs(comment);
+                    push_comment_component(
+                        &mut state.results,
+                        &mut state.tag_info_stack,
+                        rules,
+                        &escaped_comment,
+                    );
+                }
+                Component::Slot(name) => push_slot_position(state, name),
+                Component::Portal(slot, key, portal_cmpnt) => {
+                    push_slot_entry(state, slot, key, portal_cmpnt)
+                }
+                Component::Asset(kind, url) => push_asset_entry(state, kind, url),
+                Component::Island(name, props, island_cmpnt) => {
+                    push_slot_entry(state, ISLAND_MANIFEST_SLOT, name, &Component::None);
+
+                    let island = get_island_component(name, props, island_cmpnt);
+                    compose_component(builder, rules, state, &island)?;
+                }
+                Component::Scoped(style, scoped_cmpnt) => {
+                    compose_scoped(builder, rules, state, style, scoped_cmpnt)?;
+                }
+                Component::Memo(key, memo_cmpnt) => {
+                    compose_memo(builder, rules, state, key, memo_cmpnt)?;
+                }
+                Component::Boundary(boundary_cmpnt, fallback) => {
+                    compose_boundary(builder, rules, state, boundary_cmpnt, fallback)?;
+                }
+                Component::WithContext(context, context_cmpnt) => {
+                    compose_with_context(builder, rules, state, context, context_cmpnt)?;
+                }
+                Component::Lazy(lazy_cmpnt) => {
+                    let lazy_component = match state.context_stack.last() {
+                        Some(context) => get_lazy_component(lazy_cmpnt, context),
+                        _ => continue,
+                    };
+
+                    compose_component(builder, rules, state, &lazy_component)?;
+                }
+                #[cfg(feature = "async")]
+                Component::Async(async_cmpnt) => {
+                    let async_component = match async_cmpnt.get_component() {
+                        Some(component) => component,
+                        _ => match state.context_stack.last() {
+                            Some(context) => match push_unresolved(context, async_cmpnt) {
+                                Ok(_) => continue,
+                                Err(e) => return Err(e),
+                            },
+                            _ => return Err(Errors::UnresolvedAsyncComponent),
+                        },
+                    };
+
+                    compose_component(builder, rules, state, &async_component)?;
+                }
                 Component::List(list) => {
                     for cmpnt in list.iter().rev() {
                         let bit = get_bit_from_component_stack(
-                            &mut tag_info_stack,
+                            &mut state.tag_info_stack,
                             builder,
                             rules,
                             cmpnt,
@@ -73,6 +184,13 @@ pub fn compose_string(
                 let index = bit.inj_index;
                 bit.inj_index += 1;
 
+                // restore the scope of the template after an injection
+                if let (Some(scope), Some(curr)) =
+                    (bit.injection_scope.take(), state.tag_info_stack.last_mut())
+                {
+                    curr.scope = Some(scope);
+                }
+
                 let tmpl_str = match cmpnt {
                     Component::Tmpl(template, _) => template.template_str,
                     Component::TmplString(tmpl_string, _) => tmpl_string,
@@ -82,18 +200,12 @@ pub fn compose_string(
                 // template chunk
                 match template_steps.steps.get(index) {
                     Some(chunk) => {
-                        compose_steps(
-                            rules,
-                            &mut document_results,
-                            &mut tag_info_stack,
-                            tmpl_str,
-                            chunk,
-                        );
+                        compose_chunk(builder, rules, state, tmpl_str, index, chunk, bit)?;
                     }
                     _ => {
                         // at the end of template
                         // if stack depth does not match tag_infor_stack depth
-                        if bit.stack_depth != tag_info_stack.len() {
+                        if bit.stack_depth != state.tag_info_stack.len() {
                             return Err(Errors::UnbalancedTemplate(tmpl_str.to_string()));
                         }
                     }
@@ -111,19 +223,19 @@ pub fn compose_string(
                 {
                     match inj_step.kind {
                         StepKind::AttrMapInjection => {
-                            if let Err(e) =
-                                add_attr_inj(&mut tag_info_stack, &mut document_results, rules, inj)
-                            {
-                                return Err(e);
-                            };
+                            add_attr_inj(state, rules, inj)?;
                         }
 
                         // push template injection and bail early
                         StepKind::DescendantInjection => {
+                            // injected components are not part of a scoped template
+                            if let Some(curr) = state.tag_info_stack.last_mut() {
+                                bit.injection_scope = curr.scope.take();
+                            }
                             component_stack.push(cmpnt_bit);
 
                             let bit = get_bit_from_component_stack(
-                                &mut tag_info_stack,
+                                &mut state.tag_info_stack,
                                 builder,
                                 rules,
                                 inj,
@@ -144,7 +256,7 @@ pub fn compose_string(
         }
     }
 
-    Ok(document_results)
+    Ok(())
 }
 
 fn get_bit_from_component_stack<'a>(
@@ -155,7 +267,19 @@ fn get_bit_from_component_stack<'a>(
 ) -> StackBit<'a> {
     match cmpnt {
         Component::Text(_) => StackBit::Cmpnt(cmpnt),
+        Component::Comment(_) => StackBit::Cmpnt(cmpnt),
         Component::List(_) => StackBit::Cmpnt(cmpnt),
+        Component::Memo(_, _) => StackBit::Cmpnt(cmpnt),
+        Component::Slot(_) => StackBit::Cmpnt(cmpnt),
+        Component::Asset(_, _) => StackBit::Cmpnt(cmpnt),
+        Component::Scoped(_, _) => StackBit::Cmpnt(cmpnt),
+        Component::Island(_, _, _) => StackBit::Cmpnt(cmpnt),
+        Component::Portal(_, _, _) => StackBit::Cmpnt(cmpnt),
+        Component::Boundary(_, _) => StackBit::Cmpnt(cmpnt),
+        Component::WithContext(_, _) => StackBit::Cmpnt(cmpnt),
+        Component::Lazy(_) => StackBit::Cmpnt(cmpnt),
+        #[cfg(feature = "async")]
+        Component::Async(_) => StackBit::Cmpnt(cmpnt),
         Component::Tmpl(tmpl, _) => {
             let template_steps = builder.build(rules, tmpl.template_str);
             StackBit::Tmpl(
@@ -164,6 +288,8 @@ fn get_bit_from_component_stack<'a>(
                 TemplateBit {
                     inj_index: 0,
                     stack_depth: stack.len(),
+                    compiled_steps: builder.get_compiled_steps(tmpl.template_str),
+                    injection_scope: None,
                 },
             )
         }
@@ -175,20 +301,476 @@ fn get_bit_from_component_stack<'a>(
                 TemplateBit {
                     inj_index: 0,
                     stack_depth: stack.len(),
+                    compiled_steps: builder.get_compiled_steps(tmpl_string),
+                    injection_scope: None,
                 },
             )
         }
+        // shared subtrees are walked by reference, never cloned
+        Component::Shared(shared) => get_bit_from_component_stack(stack, builder, rules, shared),
         _ => StackBit::None,
     }
 }
 
+// Static chunks between injections are cached by the element a template
+// is injected within and the elements the template opened before the chunk.
+// Chunks that follow attributes of an open tag are always composed because
+// duplicate attributes rewrite earlier results.
+fn compose_chunk(
+    builder: &mut dyn TemplateBuilderImpl,
+    rules: &dyn RulesetImpl,
+    state: &mut ComposeState,
+    template_str: &str,
+    index: usize,
+    chunk: &Vec<Step>,
+    bit: &TemplateBit,
+) -> Result<(), Errors> {
+    let origin = state.results.len();
+    let frame = bit
+        .stack_depth
+        .saturating_sub(1)
+        .min(state.tag_info_stack.len());
+
+    let context = &state.tag_info_stack[frame..];
+    let cacheable = context.iter().all(|tag_info| tag_info.attrs.is_empty());
+
+    if cacheable && let Some(cached) = builder.get_chunk(rules, template_str, index, context) {
+        state.results.push_str(&cached.results);
+        state.tag_info_stack.truncate(frame);
+        state.tag_info_stack.extend(cached.tag_info_stack);
+        map_attr_positions(&mut state.tag_info_stack[frame..], |position| {
+            position + origin
+        });
+        return Ok(());
+    }
+
+    let context = match cacheable {
+        true => context.to_vec(),
+        _ => Vec::new(),
+    };
+
+    match bit.compiled_steps {
+        Some(compiled_steps) => {
+            let mut writer = StepWriter::new(rules, &mut state.results, &mut state.tag_info_stack);
+            compiled_steps(&mut writer, index)?;
+        }
+        _ => compose_steps(
+            rules,
+            &mut state.results,
+            &mut state.tag_info_stack,
+            template_str,
+            chunk,
+        )?,
+    }
+
+    // chunks that close the element they are injected within read
+    // elements outside of their context
+    if cacheable && frame < state.tag_info_stack.len() {
+        let mut chunk_results = ChunkResults {
+            results: state.results[origin..].to_string(),
+            tag_info_stack: state.tag_info_stack[frame..].to_vec(),
+        };
+        map_attr_positions(&mut chunk_results.tag_info_stack, |position| {
+            position - origin
+        });
+
+        builder.set_chunk(rules, template_str, index, &context, chunk_results);
+    }
+
+    Ok(())
+}
+
+fn map_attr_positions(tag_info_stack: &mut [TagInfo], map: impl Fn(usize) -> usize) {
+    for tag_info in tag_info_stack {
+        for attr_info in &mut tag_info.attrs {
+            attr_info.origin = map(attr_info.origin);
+            attr_info.value_origin = map(attr_info.value_origin);
+            attr_info.target = map(attr_info.target);
+        }
+    }
+}
+
+// splice cached results or render and cache them for the next document
+fn compose_memo(
+    builder: &mut dyn TemplateBuilderImpl,
+    rules: &dyn RulesetImpl,
+    state: &mut ComposeState,
+    key: &str,
+    component: &Component,
+) -> Result<(), Errors> {
+    let tag_info = match state.tag_info_stack.last() {
+        Some(curr) => curr.clone(),
+        _ => return Ok(()),
+    };
+
+    if let Some(memo) = builder.get_memo(rules, key, &tag_info) {
+        let origin = state.results.len();
+        state.results.push_str(&memo.results);
+        if let Some(curr) = state.tag_info_stack.last_mut() {
+            curr.text_format = memo.text_format;
+        }
+
+        // replay slots and portal entries of the memo
+        for mut slot in memo.slots {
+            slot.origin += origin;
+            state.slots.push(slot);
+        }
+        state.slot_entries.extend(memo.slot_entries);
+
+        return Ok(());
+    }
+
+    let origin = state.results.len();
+    let slots_len = state.slots.len();
+    let slot_entries_len = state.slot_entries.len();
+    let unresolved_len = get_unresolved_len(state);
+    compose_component(builder, rules, state, component)?;
+
+    // memos waiting on futures are incomplete
+    if unresolved_len != get_unresolved_len(state) {
+        return Ok(());
+    }
+
+    let text_format = match state.tag_info_stack.last() {
+        Some(curr) => curr.text_format.clone(),
+        _ => return Ok(()),
+    };
+
+    let mut slots = state.slots[slots_len..].to_vec();
+    for slot in &mut slots {
+        slot.origin -= origin;
+    }
+
+    builder.set_memo(
+        rules,
+        key,
+        &tag_info,
+        MemoResults {
+            results: state.results[origin..].to_string(),
+            text_format,
+            slots,
+            slot_entries: state.slot_entries[slot_entries_len..].to_vec(),
+        },
+    );
+
+    Ok(())
+}
+
+// discard partial results of a failed component and render its fallback
+fn compose_boundary(
+    builder: &mut dyn TemplateBuilderImpl,
+    rules: &dyn RulesetImpl,
+    state: &mut ComposeState,
+    component: &Component,
+    fallback: &Component,
+) -> Result<(), Errors> {
+    let origin = state.results.len();
+    let tag_info_stack = state.tag_info_stack.clone();
+    let context_depth = state.context_stack.len();
+    let slots_len = state.slots.len();
+    let slot_entries_len = state.slot_entries.len();
+
+    let error = match compose_component(builder, rules, state, component) {
+        Ok(_) => return Ok(()),
+        Err(e) => e,
+    };
+
+    state.results.truncate(origin);
+    state.tag_info_stack = tag_info_stack;
+    state.context_stack.truncate(context_depth);
+    state.slots.truncate(slots_len);
+    state.slot_entries.truncate(slot_entries_len);
+
+    if let Some(context) = state.context_stack.last() {
+        report_error(context, &error);
+    }
+
+    compose_component(builder, rules, state, fallback)
+}
+
+fn report_error(context: &RenderContext, error: &Errors) {
+    let error_hook = match context.get::<ErrorHook>() {
+        Some(error_hook) => error_hook,
+        _ => return,
+    };
+
+    // async renders report errors of the complete document only
+    #[cfg(feature = "async")]
+    if let Some(unresolved) = context.get::<Unresolved>() {
+        unresolved.push_error(error_hook, error);
+        return;
+    }
+
+    error_hook.report(error);
+}
+
+// async renders evaluate a lazy component once per context
+fn get_lazy_component(lazy_cmpnt: &LazyComponent, context: &RenderContext) -> Component {
+    #[cfg(feature = "async")]
+    if let Some(unresolved) = context.get::<Unresolved>() {
+        return unresolved.get_lazy_component(lazy_cmpnt, context);
+    }
+
+    lazy_cmpnt.get_component(context)
+}
+
+// async renders collect pending futures, other renders fail
+#[cfg(feature = "async")]
+fn push_unresolved(context: &RenderContext, async_cmpnt: &AsyncComponent) -> Result<(), Errors> {
+    if async_cmpnt.has_failed() {
+        return Err(Errors::AsyncComponentFailed);
+    }
+
+    match context.get::<Unresolved>() {
+        Some(unresolved) => {
+            unresolved.push_async_component(async_cmpnt);
+            Ok(())
+        }
+        _ => Err(Errors::UnresolvedAsyncComponent),
+    }
+}
+
+#[cfg(feature = "async")]
+fn get_unresolved_len(state: &ComposeState) -> usize {
+    match state.context_stack.last() {
+        Some(context) => match context.get::<Unresolved>() {
+            Some(unresolved) => unresolved.get_async_components_len(),
+            _ => 0,
+        },
+        _ => 0,
+    }
+}
+
+#[cfg(not(feature = "async"))]
+fn get_unresolved_len(_state: &ComposeState) -> usize {
+    0
+}
+
+fn push_slot_position(state: &mut ComposeState, name: &str) {
+    let tag_info = match state.tag_info_stack.last() {
+        Some(curr) => curr,
+        _ => return,
+    };
+
+    if tag_info.banned_path {
+        return;
+    }
+
+    state.slots.push(SlotPosition {
+        name: name.to_string(),
+        origin: state.results.len(),
+        tag_info: tag_info.clone(),
+    });
+}
+
+fn push_slot_entry(state: &mut ComposeState, slot: &str, key: &str, component: &Component) {
+    if let Some(tag_info) = state.tag_info_stack.last()
+        && tag_info.banned_path
+    {
+        return;
+    }
+
+    let context = match state.context_stack.last() {
+        Some(context) => context.clone(),
+        _ => return,
+    };
+
+    state.slot_entries.push(SlotEntry {
+        slot: slot.to_string(),
+        key: key.to_string(),
+        component: component.clone(),
+        context,
+    });
+}
+
+// assets are portal entries keyed by their kind and url
+fn push_asset_entry(state: &mut ComposeState, kind: &AssetKind, url: &str) {
+    let component = match state.context_stack.last() {
+        Some(context) => get_asset_component(context, kind, url),
+        _ => return,
+    };
+
+    let key = get_asset_key(kind, url);
+    push_slot_entry(state, get_asset_slot(kind), &key, &component);
+}
+
+// styles are portal entries keyed by their scope attribute
+fn compose_scoped(
+    builder: &mut dyn TemplateBuilderImpl,
+    rules: &dyn RulesetImpl,
+    state: &mut ComposeState,
+    style: &ScopedStyle,
+    component: &Component,
+) -> Result<(), Errors> {
+    push_slot_entry(
+        state,
+        "head",
+        style.get_attr(),
+        &style.get_style_component(),
+    );
+
+    let prev_scope = match state.tag_info_stack.last_mut() {
+        Some(curr) => curr.scope.replace(style.get_attr().to_string()),
+        _ => return Ok(()),
+    };
+
+    let results = compose_component(builder, rules, state, component);
+
+    if let Some(curr) = state.tag_info_stack.last_mut() {
+        curr.scope = prev_scope;
+    }
+
+    results
+}
+
+// render portal entries at the first position of their slot
+fn compose_slots(
+    builder: &mut dyn TemplateBuilderImpl,
+    rules: &dyn RulesetImpl,
+    state: &mut ComposeState,
+) -> Result<(), Errors> {
+    let slots = std::mem::take(&mut state.slots);
+    let mut slot_entries = std::mem::take(&mut state.slot_entries);
+
+    // entries can declare portals and assets of their own, slots are
+    // rendered again until entries add no new slot and key
+    let slot_results = loop {
+        let mut nested_entries = Vec::new();
+        let slot_results = compose_slot_results(
+            builder,
+            rules,
+            &slots,
+            &slot_entries,
+            &mut Vec::new(),
+            &mut nested_entries,
+        )?;
+
+        let mut added = false;
+        for entry in nested_entries {
+            let exists = slot_entries
+                .iter()
+                .any(|prev| prev.slot == entry.slot && prev.key == entry.key);
+            if !exists {
+                slot_entries.push(entry);
+                added = true;
+            }
+        }
+
+        if !added {
+            break slot_results;
+        }
+    };
+
+    // insert from the end of the document so origins remain valid
+    for (origin, results) in slot_results.iter().rev() {
+        state.results.insert_str(*origin, results);
+    }
+
+    if rules.get_document_memory_limit() < state.results.len() {
+        return Err(Errors::DocumentMemoryLimitExceeded(
+            rules.get_document_memory_limit(),
+            state.results.len(),
+        ));
+    }
+
+    Ok(())
+}
+
+// results of every slot at its first position, slots declared inside
+// entries are rendered when the slot has no earlier position
+fn compose_slot_results(
+    builder: &mut dyn TemplateBuilderImpl,
+    rules: &dyn RulesetImpl,
+    slots: &[SlotPosition],
+    slot_entries: &[SlotEntry],
+    positioned: &mut Vec<String>,
+    nested_entries: &mut Vec<SlotEntry>,
+) -> Result<Vec<(usize, String)>, Errors> {
+    let mut positions = Vec::new();
+    for slot in get_first_slot_positions(slots) {
+        if !positioned.contains(&slot.name) {
+            positioned.push(slot.name.clone());
+            positions.push(slot);
+        }
+    }
+
+    let mut slot_results = Vec::new();
+    for slot in positions {
+        let mut slot_state = ComposeState {
+            results: "".to_string(),
+            tag_info_stack: Vec::new(),
+            context_stack: Vec::new(),
+            slots: Vec::new(),
+            slot_entries: Vec::new(),
+        };
+
+        let mut entries = get_slot_entries(slot_entries, &slot.name);
+
+        // islands are rendered as a single manifest
+        let manifest_entry;
+        if slot.name == ISLAND_MANIFEST_SLOT && !entries.is_empty() {
+            manifest_entry = get_island_manifest_entry(&entries);
+            entries = Vec::from([&manifest_entry]);
+        }
+
+        // every entry is spaced like the slot
+        for entry in entries {
+            slot_state.tag_info_stack = Vec::from([slot.tag_info.clone()]);
+            slot_state.context_stack = Vec::from([entry.context.clone()]);
+            compose_component(builder, rules, &mut slot_state, &entry.component)?;
+        }
+
+        nested_entries.append(&mut slot_state.slot_entries);
+        let nested_results = compose_slot_results(
+            builder,
+            rules,
+            &slot_state.slots,
+            slot_entries,
+            positioned,
+            nested_entries,
+        )?;
+
+        for (origin, results) in nested_results.iter().rev() {
+            slot_state.results.insert_str(*origin, results);
+        }
+
+        slot_results.push((slot.origin, slot_state.results));
+    }
+
+    Ok(slot_results)
+}
+
+// context values are scoped to the descendants of a provider
+fn compose_with_context(
+    builder: &mut dyn TemplateBuilderImpl,
+    rules: &dyn RulesetImpl,
+    state: &mut ComposeState,
+    context: &RenderContext,
+    component: &Component,
+) -> Result<(), Errors> {
+    let scoped_context = match state.context_stack.last() {
+        Some(prev_context) => prev_context.extend(context),
+        _ => context.clone(),
+    };
+
+    state.context_stack.push(scoped_context);
+    let results = compose_component(builder, rules, state, component);
+    state.context_stack.pop();
+
+    results
+}
+
 fn add_attr_inj(
-    stack: &mut Vec<TagInfo>,
-    document_results: &mut String,
+    state: &mut ComposeState,
     rules: &dyn RulesetImpl,
     cmpnt: &Component,
 ) -> Result<(), Errors> {
-    let tag_info = match stack.last_mut() {
+    let context = match state.context_stack.last() {
+        Some(context) => context,
+        _ => return Ok(()),
+    };
+
+    let tag_info = match state.tag_info_stack.last_mut() {
         Some(curr) => curr,
         _ => return Ok(()),
     };
@@ -197,11 +779,23 @@ fn add_attr_inj(
         return Ok(());
     }
 
+    push_attr_injection(&mut state.results, rules, context, tag_info, cmpnt)?;
+
+    tag_info.text_format = TextFormat::Text;
+
+    Ok(())
+}
+
+fn push_attr_injection(
+    document_results: &mut String,
+    rules: &dyn RulesetImpl,
+    context: &RenderContext,
+    tag_info: &mut TagInfo,
+    cmpnt: &Component,
+) -> Result<(), Errors> {
     match cmpnt {
         Component::Attr(attr) => {
-            if let Err(e) = push_attr_component(document_results, tag_info, attr) {
-                return Err(e);
-            }
+            push_attr_component(document_results, tag_info, attr)?;
         }
         Component::AttrVal(attr, val) => {
             if let Err(e) = push_attr_component(document_results, tag_info, attr) {
@@ -210,29 +804,75 @@ fn add_attr_inj(
 
             push_attr_value_component(document_results, rules, tag_info, val)
         }
+        Component::AttrUrl(attr, url) => {
+            push_attr_component(document_results, tag_info, attr)?;
+
+            let val = get_url_value(url);
+            push_attr_value_component(document_results, rules, tag_info, &val)
+        }
+        Component::Classes(classes) => {
+            push_attr_component(document_results, tag_info, "class")?;
+
+            let val = get_class_value(classes);
+            push_attr_value_component(document_results, rules, tag_info, &val)
+        }
+        Component::Styles(styles) => {
+            push_attr_component(document_results, tag_info, "style")?;
+
+            let val = get_style_value(styles);
+            push_attr_value_component(document_results, rules, tag_info, &val)
+        }
+        Component::Island(name, props, _) => {
+            push_attr_component(document_results, tag_info, "data-island")?;
+            push_attr_value_component(document_results, rules, tag_info, name);
+
+            push_attr_component(document_results, tag_info, "data-props")?;
+            push_verbatim_attr_value(document_results, tag_info, &get_props_value(props));
+        }
         Component::List(attr_list) => {
             for cmpnt in attr_list {
-                match cmpnt {
-                    Component::Attr(attr) => {
-                        if let Err(e) = push_attr_component(document_results, tag_info, attr) {
-                            return Err(e);
-                        }
-                    }
-                    Component::AttrVal(attr, val) => {
-                        if let Err(e) = push_attr_component(document_results, tag_info, attr) {
-                            return Err(e);
-                        }
-                        push_attr_value_component(document_results, rules, tag_info, val)
-                    }
-                    _ => {}
-                }
+                push_attr_injection(document_results, rules, context, tag_info, cmpnt)?;
+            }
+        }
+        Component::Shared(shared) => {
+            return push_attr_injection(document_results, rules, context, tag_info, shared);
+        }
+        Component::Lazy(lazy_cmpnt) => {
+            let cmpnt = get_lazy_component(lazy_cmpnt, context);
+            return push_attr_injection(document_results, rules, context, tag_info, &cmpnt);
+        }
+        #[cfg(feature = "async")]
+        Component::Async(async_cmpnt) => {
+            let cmpnt = match async_cmpnt.get_component() {
+                Some(component) => component,
+                _ => return push_unresolved(context, async_cmpnt),
+            };
+
+            return push_attr_injection(document_results, rules, context, tag_info, &cmpnt);
+        }
+        Component::Boundary(cmpnt, fallback) => {
+            let origin = document_results.len();
+            let prev_tag_info = tag_info.clone();
+
+            // attributes are resolved when the tag closes, so duplicates
+            // of earlier attributes are validated inside the boundary
+            let mut results =
+                push_attr_injection(document_results, rules, context, tag_info, cmpnt);
+            if results.is_ok() {
+                results = validate_attrs(rules, tag_info, prev_tag_info.attrs.len());
+            }
+
+            if let Err(e) = results {
+                document_results.truncate(origin);
+                *tag_info = prev_tag_info;
+                report_error(context, &e);
+
+                return push_attr_injection(document_results, rules, context, tag_info, fallback);
             }
         }
         _ => {}
     };
 
-    tag_info.text_format = TextFormat::Text;
-
     Ok(())
 }
 
@@ -249,14 +889,47 @@ fn remove_template_glyphs(text: &str) -> String {
     safer_text
 }
 
-fn push_attr_component(results: &mut String, tag_info: &TagInfo, attr: &str) -> Result<(), Errors> {
+// comment text cannot close its comment early
+fn escape_comment_glyphs(text: &str) -> String {
+    let mut safer_text = String::from("");
+    let mut prev_glyph = ' ';
+    for glyph in text.chars() {
+        match glyph {
+            '<' => safer_text.push_str("&lt;"),
+            '>' => safer_text.push_str("&gt;"),
+            '-' => {
+                if prev_glyph == '-' {
+                    safer_text.push(' ');
+                }
+                safer_text.push(glyph);
+            }
+            _ => safer_text.push(glyph),
+        }
+        prev_glyph = glyph;
+    }
+
+    // a trailing hyphen would join the closing sequence
+    if prev_glyph == '-' {
+        safer_text.push(' ');
+    }
+
+    safer_text
+}
+
+fn push_attr_component(
+    results: &mut String,
+    tag_info: &mut TagInfo,
+    attr: &str,
+) -> Result<(), Errors> {
     if let Err(e) = attr_is_valid(attr) {
         return Err(e);
     }
 
+    let origin = results.len();
     push_formatted_space(results, tag_info);
 
     results.push_str(attr);
+    push_attr_info(tag_info, attr, origin, results.len());
 
     Ok(())
 }
@@ -292,13 +965,21 @@ fn forbidden_attr_glyph(glyph: char) -> bool {
 fn push_attr_value_component(
     results: &mut String,
     rules: &dyn RulesetImpl,
-    tag_info: &TagInfo,
+    tag_info: &mut TagInfo,
     val: &str,
 ) {
     results.push_str("=\"");
     let escaped = val.replace("\"", "&quot;");
     push_multiline_attributes(results, rules, &escaped, tag_info);
     results.push('"');
+    close_attr_info(tag_info, results.len());
+}
+
+fn push_verbatim_attr_value(results: &mut String, tag_info: &mut TagInfo, val: &str) {
+    results.push_str("=\"");
+    results.push_str(val);
+    results.push('"');
+    close_attr_info(tag_info, results.len());
 }
 
 fn push_text_component_injection(results: &mut String, stack: &mut Vec<TagInfo>, text: &str) {
@@ -311,3 +992,44 @@ fn push_text_component_injection(results: &mut String, stack: &mut Vec<TagInfo>,
 
     tag_info.text_format = TextFormat::Text;
 }
+
+fn push_comment_component(
+    results: &mut String,
+    stack: &mut [TagInfo],
+    rules: &dyn RulesetImpl,
+    comment: &str,
+) {
+    let tag_info = match stack.last_mut() {
+        Some(curr) => curr,
+        _ => return,
+    };
+
+    if tag_info.banned_path {
+        return;
+    }
+
+    // comments are text inside alt text and contentless elements
+    if rules
+        .get_close_sequence_from_alt_text_tag(&tag_info.tag)
+        .is_some()
+    {
+        return;
+    }
+
+    if rules
+        .get_close_sequence_from_contentless_tag(&tag_info.tag)
+        .is_some()
+    {
+        return;
+    }
+
+    if !tag_info.preformatted_text_path {
+        push_formatted_space(results, tag_info);
+    }
+
+    results.push_str("<!--");
+    results.push_str(comment);
+    results.push_str("-->");
+
+    tag_info.text_format = TextFormat::Text;
+}
@@ -1,8 +1,14 @@
+mod attributes;
 mod compose_steps;
 mod compose_string;
+mod slots;
 mod tag_info;
 mod template_builder;
 mod text_components;
 
-pub use compose_string::compose_string;
-pub use template_builder::TemplateBuilderImpl;
+pub(crate) use compose_steps::compose_step;
+pub use compose_steps::{CompiledSteps, StaticFrame, StepWriter};
+pub use compose_string::{compose_string, compose_string_with_context};
+pub use slots::{SlotEntry, SlotPosition};
+pub use tag_info::{TagInfo, TextFormat};
+pub use template_builder::{ChunkResults, MemoResults, TemplateBuilderImpl};
@@ -0,0 +1,57 @@
+use crate::components::Component;
+use crate::context::RenderContext;
+use crate::documents::tag_info::TagInfo;
+
+/*
+    Portals render components at a named slot anywhere in a document.
+
+    Slot positions and portal entries are collected while a document
+    is composed. Entries are rendered at the first position of their
+    slot after the rest of the document is composed.
+*/
+
+// location of a slot in the results of a document
+#[derive(Debug, Clone, Eq, PartialEq)]
+pub struct SlotPosition {
+    pub name: String,
+    pub origin: usize,
+    pub tag_info: TagInfo,
+}
+
+// a component rendered at a slot with the context it was registered in
+#[derive(Debug, Clone, Eq, PartialEq)]
+pub struct SlotEntry {
+    pub slot: String,
+    pub key: String,
+    pub component: Component,
+    pub context: RenderContext,
+}
+
+// first position of every slot in document order
+pub fn get_first_slot_positions(slots: &[SlotPosition]) -> Vec<&SlotPosition> {
+    let mut positions: Vec<&SlotPosition> = Vec::new();
+    for slot in slots {
+        if !positions.iter().any(|position| position.name == slot.name) {
+            positions.push(slot);
+        }
+    }
+
+    positions
+}
+
+// entries of a slot, later entries replace earlier entries with the same key
+pub fn get_slot_entries<'a>(entries: &'a [SlotEntry], slot: &str) -> Vec<&'a SlotEntry> {
+    let mut slot_entries: Vec<&SlotEntry> = Vec::new();
+    for entry in entries {
+        if entry.slot != slot {
+            continue;
+        }
+
+        match slot_entries.iter().position(|prev| prev.key == entry.key) {
+            Some(index) => slot_entries[index] = entry,
+            _ => slot_entries.push(entry),
+        }
+    }
+
+    slot_entries
+}
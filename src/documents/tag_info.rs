@@ -1,7 +1,7 @@
 use crate::template_steps::RulesetImpl;
 
 // describes how to handle elements and spacing
-#[derive(Debug, Clone, Eq, PartialEq)]
+#[derive(Debug, Clone, Eq, Hash, PartialEq)]
 pub enum TextFormat {
     Initial,
     LineSpace,
@@ -9,13 +9,24 @@ pub enum TextFormat {
     Text,
 }
 
-#[derive(Debug, Clone, Eq, PartialEq)]
+// location of an attribute in the open tag of an element
+#[derive(Debug, Clone, Eq, Hash, PartialEq)]
+pub struct AttrInfo {
+    pub attr: String,
+    pub origin: usize,
+    pub value_origin: usize,
+    pub target: usize,
+}
+
+#[derive(Debug, Clone, Eq, Hash, PartialEq)]
 pub struct TagInfo {
+    pub attrs: Vec<AttrInfo>,
     pub banned_path: bool,
     pub indent_count: usize,
     pub inline_el: bool,
     pub embedded_content: String,
     pub preformatted_text_path: bool,
+    pub scope: Option<String>,
     pub tag: String,
     pub text_format: TextFormat,
     pub void_el: bool,
@@ -24,11 +35,13 @@ pub struct TagInfo {
 impl TagInfo {
     pub fn get_root(rules: &dyn RulesetImpl) -> TagInfo {
         TagInfo {
+            attrs: Vec::new(),
             banned_path: false,
             indent_count: 0,
             inline_el: true,
             embedded_content: rules.get_initial_embedded_content().to_string(),
             preformatted_text_path: false,
+            scope: None,
             tag: ":root".to_string(),
             text_format: TextFormat::Initial,
             void_el: false,
@@ -38,6 +51,7 @@ impl TagInfo {
     pub fn from(rules: &dyn RulesetImpl, prev_tag_info: &TagInfo, tag: &str) -> TagInfo {
         let mut tag_info = prev_tag_info.clone();
 
+        tag_info.attrs = Vec::new();
         tag_info.tag = tag.to_string();
         tag_info.void_el = rules.tag_is_void_el(tag);
         tag_info.inline_el = rules.tag_is_inline_el(tag);
@@ -47,7 +61,13 @@ impl TagInfo {
             tag_info.embedded_content = tag.to_string();
         }
 
-        if rules.tag_is_preformatted_text_el(&tag_info.tag) {
+        // template content is a separate document fragment
+        if rules.tag_is_template_el(tag) {
+            tag_info.embedded_content = rules.get_initial_embedded_content().to_string();
+            tag_info.scope = None;
+        }
+
+        if rules.tag_is_preformatted_text_el(&tag_info.tag) || rules.tag_is_rcdata_el(tag) {
             tag_info.preformatted_text_path = true;
         }
 
@@ -55,6 +75,10 @@ impl TagInfo {
             tag_info.banned_path = true;
         }
 
+        if !rules.keep_comments() && rules.tag_is_comment_el(tag) {
+            tag_info.banned_path = true;
+        }
+
         if rules.respect_indentation()
             && !rules.tag_is_void_el(&tag_info.tag)
             && !rules.tag_is_inline_el(tag)
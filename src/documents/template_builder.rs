@@ -1,5 +1,66 @@
+use crate::documents::compose_steps::CompiledSteps;
+use crate::documents::slots::{SlotEntry, SlotPosition};
+use crate::documents::tag_info::{TagInfo, TextFormat};
 use crate::template_steps::{RulesetImpl, TemplateSteps};
 
+// rendered results of a memo component, the spacing it leaves behind,
+// and the slots and portal entries found while rendering
+#[derive(Debug, Clone, Eq, PartialEq)]
+pub struct MemoResults {
+    pub results: String,
+    pub text_format: TextFormat,
+    pub slots: Vec<SlotPosition>,
+    pub slot_entries: Vec<SlotEntry>,
+}
+
+// rendered results of a template chunk and the tag info stack it leaves
+// behind, attribute positions are relative to the start of the chunk
+#[derive(Debug, Clone, Eq, PartialEq)]
+pub struct ChunkResults {
+    pub results: String,
+    pub tag_info_stack: Vec<TagInfo>,
+}
+
 pub trait TemplateBuilderImpl {
     fn build(&mut self, rules: &dyn RulesetImpl, template_str: &str) -> TemplateSteps;
+    // templates without compiled steps fall back to interpreting template steps
+    fn get_compiled_steps(&mut self, _template_str: &str) -> Option<CompiledSteps> {
+        None
+    }
+    // template chunks without cached results are composed from steps
+    fn get_chunk(
+        &mut self,
+        _rules: &dyn RulesetImpl,
+        _template_str: &str,
+        _index: usize,
+        _tag_info_stack: &[TagInfo],
+    ) -> Option<ChunkResults> {
+        None
+    }
+    fn set_chunk(
+        &mut self,
+        _rules: &dyn RulesetImpl,
+        _template_str: &str,
+        _index: usize,
+        _tag_info_stack: &[TagInfo],
+        _chunk: ChunkResults,
+    ) {
+    }
+    // memo components without cached results are rendered like components
+    fn get_memo(
+        &mut self,
+        _rules: &dyn RulesetImpl,
+        _key: &str,
+        _tag_info: &TagInfo,
+    ) -> Option<MemoResults> {
+        None
+    }
+    fn set_memo(
+        &mut self,
+        _rules: &dyn RulesetImpl,
+        _key: &str,
+        _tag_info: &TagInfo,
+        _memo: MemoResults,
+    ) {
+    }
 }
@@ -0,0 +1,257 @@
+use crate::components::{AttrNumber, Component, attr_bool, attr_num, attr_opt, attr_url, attr_val};
+use crate::document_builders::is_void_el;
+use std::fmt;
+
+/*
+    A typed alternative to string templates.
+
+    Elements build the same components as tmpl_string:
+    <tag {attributes}>{descendants}</tag>
+
+    Void elements like <img> and <input> are built by functions
+    that return a VoidElement. A void element has no descendants
+    so `img().child(..)` does not compile.
+
+    Void tags passed to el() with descendants are closed like other
+    elements, html documents reject them as unbalanced templates:
+    el("br").child(text("lost")) -> <br {}>{}</br>
+*/
+
+#[derive(Debug, Clone, Eq, PartialEq)]
+pub struct Element {
+    tag: &'static str,
+    attrs: Vec<Component>,
+    classes: Vec<String>,
+    styles: Vec<(String, String)>,
+    descendants: Vec<Component>,
+}
+
+#[derive(Debug, Clone, Eq, PartialEq)]
+pub struct VoidElement {
+    tag: &'static str,
+    attrs: Vec<Component>,
+    classes: Vec<String>,
+    styles: Vec<(String, String)>,
+}
+
+// shared attribute api of elements and void elements
+macro_rules! impl_attr_methods {
+    ($element:ty) => {
+        impl $element {
+            pub fn attr(mut self, attr_str: &str, value_txt: &str) -> Self {
+                self.attrs.push(attr_val(attr_str, value_txt));
+                self
+            }
+
+            pub fn attr_bool(mut self, attr_str: &str, value: bool) -> Self {
+                self.attrs.push(attr_bool(attr_str, value));
+                self
+            }
+
+            pub fn attr_num<T: AttrNumber>(mut self, attr_str: &str, value: T) -> Self {
+                self.attrs.push(attr_num(attr_str, value));
+                self
+            }
+
+            pub fn attr_opt<T: fmt::Display>(mut self, attr_str: &str, value: Option<T>) -> Self {
+                self.attrs.push(attr_opt(attr_str, value));
+                self
+            }
+
+            pub fn attr_url(mut self, attr_str: &str, url: &str) -> Self {
+                self.attrs.push(attr_url(attr_str, url));
+                self
+            }
+
+            // any attribute component: attr, attr_val, classes, lists of attributes
+            pub fn attr_component(mut self, component: Component) -> Self {
+                self.attrs.push(component);
+                self
+            }
+
+            pub fn class(mut self, class_name: &str) -> Self {
+                self.classes.push(class_name.to_string());
+                self
+            }
+
+            pub fn class_if(mut self, condition: bool, class_name: &str) -> Self {
+                if condition {
+                    self.classes.push(class_name.to_string());
+                }
+                self
+            }
+
+            pub fn style(mut self, property: &str, value: &str) -> Self {
+                self.styles.push((property.to_string(), value.to_string()));
+                self
+            }
+        }
+    };
+}
+
+impl_attr_methods!(Element);
+impl_attr_methods!(VoidElement);
+
+impl Element {
+    pub fn child<T: Into<Component>>(mut self, component: T) -> Element {
+        self.descendants.push(component.into());
+        self
+    }
+
+    pub fn children<I, T>(mut self, components: I) -> Element
+    where
+        I: IntoIterator<Item = T>,
+        T: Into<Component>,
+    {
+        for component in components {
+            self.descendants.push(component.into());
+        }
+        self
+    }
+
+    pub fn build(self) -> Component {
+        let attrs = get_attr_components(self.attrs, self.classes, self.styles);
+
+        // void tags passed to el() render without descendants
+        if is_void_el(self.tag) && self.descendants.is_empty() {
+            return Component::TmplString(format!("<{} {{}}>", self.tag), Vec::from([attrs]));
+        }
+
+        Component::TmplString(
+            format!("<{} {{}}>{{}}</{}>", self.tag, self.tag),
+            Vec::from([attrs, Component::List(self.descendants)]),
+        )
+    }
+}
+
+impl VoidElement {
+    pub fn build(self) -> Component {
+        let attrs = get_attr_components(self.attrs, self.classes, self.styles);
+        Component::TmplString(format!("<{} {{}}>", self.tag), Vec::from([attrs]))
+    }
+}
+
+impl From<Element> for Component {
+    fn from(element: Element) -> Component {
+        element.build()
+    }
+}
+
+impl From<VoidElement> for Component {
+    fn from(element: VoidElement) -> Component {
+        element.build()
+    }
+}
+
+fn get_attr_components(
+    mut attrs: Vec<Component>,
+    classes: Vec<String>,
+    styles: Vec<(String, String)>,
+) -> Component {
+    if !classes.is_empty() {
+        attrs.push(Component::Classes(classes));
+    }
+
+    if !styles.is_empty() {
+        attrs.push(Component::Styles(styles));
+    }
+
+    Component::List(attrs)
+}
+
+pub fn el(tag: &'static str) -> Element {
+    Element {
+        tag,
+        attrs: Vec::new(),
+        classes: Vec::new(),
+        styles: Vec::new(),
+        descendants: Vec::new(),
+    }
+}
+
+pub fn void_el(tag: &'static str) -> VoidElement {
+    VoidElement {
+        tag,
+        attrs: Vec::new(),
+        classes: Vec::new(),
+        styles: Vec::new(),
+    }
+}
+
+#[derive(Debug, Clone, Copy, Eq, PartialEq)]
+pub enum ShadowRootMode {
+    Open,
+    Closed,
+}
+
+// a declarative shadow root with isolated styles, place it first inside a custom element
+pub fn shadow_root(mode: ShadowRootMode, css: &str, content: Component) -> Component {
+    let mode_str = match mode {
+        ShadowRootMode::Open => "open",
+        ShadowRootMode::Closed => "closed",
+    };
+
+    let template_str = match css.is_empty() {
+        true => format!("<template shadowrootmode=\"{}\">{{}}</template>", mode_str),
+        _ => format!(
+            "<template shadowrootmode=\"{}\"><style>{}</style>{{}}</template>",
+            mode_str,
+            css.replace("</", "<\\/")
+        ),
+    };
+
+    Component::TmplString(template_str, Vec::from([content]))
+}
+
+// https://html.spec.whatwg.org/multipage/syntax.html#void-elements
+pub fn area() -> VoidElement {
+    void_el("area")
+}
+
+pub fn base() -> VoidElement {
+    void_el("base")
+}
+
+pub fn br() -> VoidElement {
+    void_el("br")
+}
+
+pub fn col() -> VoidElement {
+    void_el("col")
+}
+
+pub fn embed() -> VoidElement {
+    void_el("embed")
+}
+
+pub fn hr() -> VoidElement {
+    void_el("hr")
+}
+
+pub fn img() -> VoidElement {
+    void_el("img")
+}
+
+pub fn input() -> VoidElement {
+    void_el("input")
+}
+
+pub fn link() -> VoidElement {
+    void_el("link")
+}
+
+pub fn meta() -> VoidElement {
+    void_el("meta")
+}
+
+pub fn source() -> VoidElement {
+    void_el("source")
+}
+
+pub fn track() -> VoidElement {
+    void_el("track")
+}
+
+pub fn wbr() -> VoidElement {
+    void_el("wbr")
+}
@@ -1,11 +1,18 @@
 use std::error;
 use std::fmt;
 
+// variants depend on features, match with a wildcard arm
 #[derive(Debug, Clone, PartialEq)]
+#[non_exhaustive]
 pub enum Errors {
     InvalidAttribute(String, usize, char),
     UnbalancedTemplate(String),
     DocumentMemoryLimitExceeded(usize, usize),
+    DuplicateAttribute(String, String),
+    #[cfg(feature = "async")]
+    UnresolvedAsyncComponent,
+    #[cfg(feature = "async")]
+    AsyncComponentFailed,
 }
 
 impl error::Error for Errors {}
@@ -30,6 +37,21 @@ impl fmt::Display for Errors {
                     document_length, memory_limit
                 )
             }
+            Errors::DuplicateAttribute(attribute, tag) => {
+                write!(
+                    f,
+                    "The following attribute: {}\nis declared more than once on the element: {}",
+                    attribute, tag
+                )
+            }
+            #[cfg(feature = "async")]
+            Errors::UnresolvedAsyncComponent => {
+                write!(f, "An async component was rendered before it was resolved")
+            }
+            #[cfg(feature = "async")]
+            Errors::AsyncComponentFailed => {
+                write!(f, "An async component panicked while it was resolved")
+            }
         }
     }
 }
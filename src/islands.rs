@@ -0,0 +1,91 @@
+use crate::components::Component;
+use crate::context::RenderContext;
+use crate::documents::SlotEntry;
+
+/*
+    Islands are interactive parts of a static document.
+
+    An island renders its server html inside a marker element:
+    <coyote-island data-island="counter" data-props="{&quot;count&quot;:1}">
+
+    Props are serialized by the caller and written verbatim,
+    only glyphs that could end the attribute value are escaped.
+
+    Island names are collected at the "islands" slot and rendered
+    as a json manifest so a client loader can hydrate only the
+    islands used by a document:
+    <script type="application/json" id="coyote-islands">["counter"]</script>
+*/
+
+pub const ISLAND_MANIFEST_SLOT: &str = "islands";
+
+// an island injected as attributes renders its marker attributes
+pub fn get_island_component(name: &str, props: &str, component: &Component) -> Component {
+    Component::TmplString(
+        "<coyote-island {}>{}</coyote-island>".to_string(),
+        Vec::from([
+            Component::Island(
+                name.to_string(),
+                props.to_string(),
+                Box::new(Component::None),
+            ),
+            component.clone(),
+        ]),
+    )
+}
+
+// props are not reformatted so they round-trip
+pub fn get_props_value(props: &str) -> String {
+    let mut escaped = String::from("");
+    for glyph in props.chars() {
+        match glyph {
+            '&' => escaped.push_str("&amp;"),
+            '"' => escaped.push_str("&quot;"),
+            '<' => escaped.push_str("&lt;"),
+            _ => escaped.push(glyph),
+        }
+    }
+
+    escaped
+}
+
+// island entries become a single manifest entry
+pub fn get_island_manifest_entry(entries: &[&SlotEntry]) -> SlotEntry {
+    let mut names = Vec::new();
+    for entry in entries {
+        names.push(format!("\"{}\"", get_json_escaped(&entry.key)));
+    }
+
+    SlotEntry {
+        slot: ISLAND_MANIFEST_SLOT.to_string(),
+        key: ISLAND_MANIFEST_SLOT.to_string(),
+        component: Component::TmplString(
+            format!(
+                "<script type=\"application/json\" id=\"coyote-islands\">[{}]</script>",
+                names.join(",")
+            ),
+            Vec::new(),
+        ),
+        context: RenderContext::new(),
+    }
+}
+
+// json strings that cannot close a script element
+fn get_json_escaped(text: &str) -> String {
+    let mut escaped = String::from("");
+    for glyph in text.chars() {
+        match glyph {
+            '"' => escaped.push_str("\\\""),
+            '\\' => escaped.push_str("\\\\"),
+            '<' => escaped.push_str("\\u003c"),
+            '>' => escaped.push_str("\\u003e"),
+            '&' => escaped.push_str("\\u0026"),
+            _ => match glyph.is_control() {
+                true => escaped.push_str(&format!("\\u{:04x}", glyph as u32)),
+                _ => escaped.push(glyph),
+            },
+        }
+    }
+
+    escaped
+}
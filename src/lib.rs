@@ -1,12 +1,50 @@
+mod assets;
+#[cfg(feature = "async")]
+mod async_components;
 mod components;
+mod context;
+#[cfg(feature = "serde")]
+mod deserialize;
 mod document_builders;
 mod documents;
+mod elements;
 mod errors;
+mod islands;
+mod scoped_styles;
+mod template_compiler;
+mod template_registry;
 mod template_steps;
+mod traversal;
 
+pub use assets::{AssetKind, AssetManifest};
+#[cfg(feature = "async")]
+pub use async_components::{
+    AsyncComponent, compose_string_async, compose_string_async_concurrently,
+};
 pub use components::*;
+pub use context::{ErrorHook, LazyComponent, RenderContext};
 pub use document_builders::{
-    DocumentParams, Html, HtmlOnly, HtmlOnlyRules, HtmlRules, Xml, XmlRules,
+    DefaultBuilder, Document, DocumentParams, ElementSet, ElementSets, Html, HtmlOnly,
+    HtmlOnlyRules, HtmlRules, LayeredRules, Xml, XmlRules,
+};
+pub use documents::{TemplateBuilderImpl, compose_string, compose_string_with_context};
+pub use elements::*;
+pub use errors::Errors;
+pub use scoped_styles::ScopedStyle;
+pub use template_compiler::{CompileError, compile_templates, get_template_module};
+pub use template_registry::TemplateRegistry;
+pub use template_steps::{DuplicateAttrPolicy, RulesetImpl, TemplateSteps, compose};
+pub use traversal::{
+    find_all, get_descendants, is_attr_component, map_attrs, map_components, map_text, walk,
 };
-pub use documents::{TemplateBuilderImpl, compose_string};
-pub use template_steps::{RulesetImpl, TemplateSteps, compose};
+
+// compose internals reached by generated template modules and
+// template builders, not part of the stable api
+#[doc(hidden)]
+pub mod __private {
+    pub use crate::documents::{
+        ChunkResults, CompiledSteps, MemoResults, SlotEntry, SlotPosition, StaticFrame, StepWriter,
+        TagInfo, TextFormat,
+    };
+    pub use crate::template_steps::{Step, StepKind};
+}
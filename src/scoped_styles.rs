@@ -0,0 +1,267 @@
+use crate::components::Component;
+
+/*
+    Scoped styles are rewritten once when they are created.
+
+    Every selector is scoped by a hashed attribute like
+    `data-card-1a2b3c4d` and every element written by the templates
+    of a scoped component receives that attribute.
+
+    .card p:hover { color: red; }
+    becomes
+    .card p[data-card-1a2b3c4d]:hover { color: red; }
+*/
+
+#[derive(Debug, Clone, Eq, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct ScopedStyle {
+    attr: String,
+    css: String,
+}
+
+impl ScopedStyle {
+    pub fn new(scope: &str, css: &str) -> ScopedStyle {
+        let attr = get_scope_attr(scope, css);
+        let css = get_scoped_css(css, &attr);
+
+        ScopedStyle { attr, css }
+    }
+
+    // attribute added to every element of a scoped template
+    pub fn get_attr(&self) -> &str {
+        &self.attr
+    }
+
+    pub fn get_css(&self) -> &str {
+        &self.css
+    }
+
+    // a style element rendered once per document at the "head" slot
+    pub fn get_style_component(&self) -> Component {
+        let css = self.css.replace("</", "<\\/");
+        Component::TmplString(format!("<style>{}</style>", css), Vec::new())
+    }
+}
+
+fn get_scope_attr(scope: &str, css: &str) -> String {
+    let mut attr = "data-".to_string();
+    for glyph in scope.chars() {
+        match glyph.is_ascii_alphanumeric() {
+            true => attr.push(glyph.to_ascii_lowercase()),
+            _ => attr.push('-'),
+        }
+    }
+
+    attr.push('-');
+    attr.push_str(&format!("{:08x}", get_hash(scope, css)));
+
+    attr
+}
+
+// FNV-1a, stable across builds and platforms
+pub(crate) fn get_hash(name: &str, text: &str) -> u32 {
+    let mut hash: u32 = 0x811c9dc5;
+    for byte in name.bytes().chain([0]).chain(text.bytes()) {
+        hash ^= byte as u32;
+        hash = hash.wrapping_mul(0x01000193);
+    }
+
+    hash
+}
+
+fn get_scoped_css(css: &str, attr: &str) -> String {
+    let mut results = String::from("");
+    let mut index = 0;
+
+    while index < css.len() {
+        let remaining = &css[index..];
+        let trimmed = remaining.trim_start();
+        if trimmed.len() < remaining.len() {
+            results.push_str(&remaining[..remaining.len() - trimmed.len()]);
+            index += remaining.len() - trimmed.len();
+            continue;
+        }
+
+        // comments are removed
+        if remaining.starts_with("/*") {
+            index += match remaining.find("*/") {
+                Some(end) => end + 2,
+                _ => remaining.len(),
+            };
+            continue;
+        }
+
+        let prelude_end = match get_index_of_prelude_end(remaining) {
+            Some(prelude_end) => prelude_end,
+            _ => {
+                results.push_str(remaining);
+                break;
+            }
+        };
+
+        let prelude = &remaining[..prelude_end];
+
+        // at-rule statements like @import
+        if remaining[prelude_end..].starts_with(';') {
+            results.push_str(prelude);
+            results.push(';');
+            index += prelude_end + 1;
+            continue;
+        }
+
+        let block_end = get_index_of_block_end(remaining, prelude_end);
+        let block = &remaining[prelude_end + 1..block_end];
+        let trimmed_prelude = prelude.trim();
+
+        match trimmed_prelude.starts_with('@') {
+            true => {
+                results.push_str(trimmed_prelude);
+                results.push_str(" {");
+                match at_rule_has_nested_rules(trimmed_prelude) {
+                    true => results.push_str(&get_scoped_css(block, attr)),
+                    _ => results.push_str(block),
+                }
+            }
+            _ => {
+                results.push_str(&get_scoped_selectors(trimmed_prelude, attr));
+                results.push_str(" {");
+                results.push_str(block);
+            }
+        }
+
+        results.push('}');
+        index += block_end + 1;
+    }
+
+    results
+}
+
+fn at_rule_has_nested_rules(prelude: &str) -> bool {
+    let name = match prelude[1..]
+        .split(|glyph: char| !glyph.is_alphanumeric() && glyph != '-')
+        .next()
+    {
+        Some(name) => name,
+        _ => return false,
+    };
+
+    matches!(
+        name,
+        "container" | "document" | "layer" | "media" | "scope" | "supports"
+    )
+}
+
+fn get_index_of_prelude_end(css: &str) -> Option<usize> {
+    let mut quote: Option<char> = None;
+    for (index, glyph) in css.char_indices() {
+        if let Some(quote_glyph) = quote {
+            if glyph == quote_glyph {
+                quote = None;
+            }
+            continue;
+        }
+
+        match glyph {
+            '"' | '\'' => quote = Some(glyph),
+            '{' | ';' => return Some(index),
+            _ => {}
+        }
+    }
+
+    None
+}
+
+// index of the right bracket that closes the block starting at `origin`
+fn get_index_of_block_end(css: &str, origin: usize) -> usize {
+    let mut depth = 0;
+    let mut quote: Option<char> = None;
+    for (index, glyph) in css[origin..].char_indices() {
+        if let Some(quote_glyph) = quote {
+            if glyph == quote_glyph {
+                quote = None;
+            }
+            continue;
+        }
+
+        match glyph {
+            '"' | '\'' => quote = Some(glyph),
+            '{' => depth += 1,
+            '}' => {
+                depth -= 1;
+                if depth == 0 {
+                    return origin + index;
+                }
+            }
+            _ => {}
+        }
+    }
+
+    css.len()
+}
+
+fn get_scoped_selectors(selectors: &str, attr: &str) -> String {
+    let mut scoped_selectors = Vec::new();
+    for selector in split_outside_of_parens(selectors, ',') {
+        scoped_selectors.push(get_scoped_selector(selector.trim(), attr));
+    }
+
+    scoped_selectors.join(", ")
+}
+
+// the scope attribute is added to the last compound selector
+// before any pseudo-classes or pseudo-elements
+fn get_scoped_selector(selector: &str, attr: &str) -> String {
+    let mut depth = 0;
+    let mut compound_origin = 0;
+    let mut pseudo_origin: Option<usize> = None;
+
+    for (index, glyph) in selector.char_indices() {
+        match glyph {
+            '(' | '[' => depth += 1,
+            ')' | ']' => depth -= 1,
+            ':' if depth == 0 && pseudo_origin.is_none() => pseudo_origin = Some(index),
+            '>' | '+' | '~' if depth == 0 => {
+                compound_origin = index + 1;
+                pseudo_origin = None;
+            }
+            _ if depth == 0 && glyph.is_whitespace() => {
+                compound_origin = index + 1;
+                pseudo_origin = None;
+            }
+            _ => {}
+        }
+    }
+
+    let insert_index = match pseudo_origin {
+        Some(pseudo_index) if compound_origin <= pseudo_index => pseudo_index,
+        _ => selector.len(),
+    };
+
+    format!(
+        "{}[{}]{}",
+        &selector[..insert_index],
+        attr,
+        &selector[insert_index..]
+    )
+}
+
+fn split_outside_of_parens(text: &str, separator: char) -> Vec<&str> {
+    let mut parts = Vec::new();
+    let mut depth = 0;
+    let mut origin = 0;
+
+    for (index, glyph) in text.char_indices() {
+        match glyph {
+            '(' | '[' => depth += 1,
+            ')' | ']' => depth -= 1,
+            _ if glyph == separator && depth == 0 => {
+                parts.push(&text[origin..index]);
+                origin = index + glyph.len_utf8();
+            }
+            _ => {}
+        }
+    }
+    parts.push(&text[origin..]);
+
+    parts
+}
@@ -0,0 +1,876 @@
+use crate::components::Component;
+use crate::document_builders::DefaultBuilder;
+use crate::documents::{StepWriter, TagInfo, TextFormat, compose_step, compose_string};
+use crate::errors::Errors;
+use crate::scoped_styles::get_hash;
+use crate::template_registry::get_template_paths;
+use crate::template_steps::{
+    RulesetImpl, Step, StepKind, TemplateSteps, compose, get_text_from_step,
+};
+use std::error;
+use std::fmt;
+use std::fs;
+use std::io;
+use std::path::Path;
+
+/*
+    Compile a directory of templates into a rust module from a build script.
+
+    Every template is validated with a ruleset and becomes a function
+    component with one parameter per injection:
+    partials/nav.html -> pub fn partials_nav(attrs_0, descendants_1) -> Component
+
+    Parsed steps are written out so document builders can skip parsing.
+
+    Template chunks are also compiled into functions that call a step
+    writer in order without interpreting steps:
+    <p>{}</p> -> writer.push_element("p"); writer.close_element();
+
+    Chunks are composed at build time within every element a template
+    could be injected within. Results that are the same within every
+    element, apart from indentation, are pushed pre-formatted:
+    <p>hai</p> -> writer.push_static(&frame, &["<p>hai</p>"]);
+
+    Compiled chunks are keyed by the ruleset decisions they were
+    composed with so other rulesets interpret their templates.
+*/
+
+const RUST_KEYWORDS: [&str; 51] = [
+    "abstract", "as", "async", "await", "become", "box", "break", "const", "continue", "crate",
+    "do", "dyn", "else", "enum", "extern", "false", "final", "fn", "for", "gen", "if", "impl",
+    "in", "let", "loop", "macro", "match", "mod", "move", "mut", "override", "priv", "pub", "ref",
+    "return", "self", "static", "struct", "super", "trait", "true", "try", "type", "typeof",
+    "unsafe", "unsized", "use", "virtual", "where", "while", "yield",
+];
+
+const TEXT_FORMATS: [TextFormat; 4] = [
+    TextFormat::Initial,
+    TextFormat::LineSpace,
+    TextFormat::Space,
+    TextFormat::Text,
+];
+
+// indentation of the element a template is injected within, results
+// indented by 0, 1, and 2 tabs reveal where indentation is pushed
+const INDENTS: [usize; 3] = [0, 1, 2];
+
+// templates with too many unknown text formats are composed step by step
+const VARIANTS_LIMIT: usize = 256;
+
+#[derive(Debug)]
+pub enum CompileError {
+    Io(io::Error),
+    InvalidTemplate(String, Errors),
+    DuplicateName(String, String),
+}
+
+impl error::Error for CompileError {}
+
+impl fmt::Display for CompileError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            CompileError::Io(e) => write!(f, "{}", e),
+            CompileError::InvalidTemplate(name, e) => {
+                write!(f, "The following template is invalid: {}\n{}", name, e)
+            }
+            CompileError::DuplicateName(name, fn_name) => {
+                write!(
+                    f,
+                    "The following template: {}\nshares the function name: {}",
+                    name, fn_name
+                )
+            }
+        }
+    }
+}
+
+struct CompiledTemplate {
+    fn_name: String,
+    const_name: String,
+    rules_key: String,
+    template_str: String,
+    template_steps: TemplateSteps,
+    chunks: Vec<CompiledChunk>,
+}
+
+// a template composed within one element it could be injected within
+#[derive(Clone)]
+struct Variant {
+    indent: usize,
+    results: String,
+    stack: Vec<TagInfo>,
+}
+
+enum StaticOp {
+    Truncate(usize),
+    SetTextFormat(usize, TextFormat),
+    PushElement(String, TextFormat),
+}
+
+enum ChunkCall {
+    Steps(usize, usize),
+    Static(Vec<String>, Vec<StaticOp>),
+}
+
+struct CompiledChunk {
+    depth: usize,
+    calls: Vec<ChunkCall>,
+}
+
+// consecutive units with pre-formatted results
+struct StaticRun {
+    pre: Vec<Vec<TagInfo>>,
+    start: usize,
+    end: usize,
+    results: String,
+    points: Vec<usize>,
+    min_len: usize,
+}
+
+// write the module of a template directory, intended for build scripts:
+// include!(concat!(env!("OUT_DIR"), "/templates.rs"));
+pub fn compile_templates<P: AsRef<Path>, Q: AsRef<Path>>(
+    rules: &dyn RulesetImpl,
+    dir: P,
+    out_path: Q,
+) -> Result<(), CompileError> {
+    let module = get_template_module(rules, &dir)?;
+
+    println!("cargo:rerun-if-changed={}", dir.as_ref().display());
+
+    match fs::write(out_path, module) {
+        Ok(_) => Ok(()),
+        Err(e) => Err(CompileError::Io(e)),
+    }
+}
+
+pub fn get_template_module<P: AsRef<Path>>(
+    rules: &dyn RulesetImpl,
+    dir: P,
+) -> Result<String, CompileError> {
+    let paths = match get_template_paths(dir.as_ref()) {
+        Ok(paths) => paths,
+        Err(e) => return Err(CompileError::Io(e)),
+    };
+
+    let mut compiled: Vec<CompiledTemplate> = Vec::new();
+    for (name, path) in paths {
+        let template_str = match fs::read_to_string(&path) {
+            Ok(template_str) => template_str,
+            Err(e) => return Err(CompileError::Io(e)),
+        };
+
+        let fn_name = get_fn_name(&name);
+        if compiled.iter().any(|template| template.fn_name == fn_name) {
+            return Err(CompileError::DuplicateName(name, fn_name));
+        }
+
+        let template_steps = compose(rules, &template_str);
+        if let Err(e) = validate_template(rules, &template_str, &template_steps) {
+            return Err(CompileError::InvalidTemplate(name, e));
+        }
+
+        compiled.push(CompiledTemplate {
+            const_name: get_const_name(&fn_name),
+            fn_name,
+            rules_key: get_rules_key(rules, &template_str),
+            chunks: get_compiled_chunks(rules, &template_str, &template_steps),
+            template_str,
+            template_steps,
+        });
+    }
+
+    Ok(get_module_str(&compiled))
+}
+
+// render every template with empty injections
+fn validate_template(
+    rules: &dyn RulesetImpl,
+    template_str: &str,
+    template_steps: &TemplateSteps,
+) -> Result<(), Errors> {
+    let injections = Vec::from_iter(template_steps.injs.iter().map(|_| Component::None));
+    let component = Component::TmplString(template_str.to_string(), injections);
+
+    match compose_string(&mut DefaultBuilder::new(), rules, &component) {
+        Ok(_) => Ok(()),
+        Err(e) => Err(e),
+    }
+}
+
+fn get_module_str(compiled: &[CompiledTemplate]) -> String {
+    let mut module = String::from("// generated by coyotes::compile_templates, do not edit\n");
+
+    for template in compiled {
+        module.push_str(&format!(
+            "\npub const {}: &str = {:?};\n",
+            template.const_name, template.template_str
+        ));
+
+        let mut params = Vec::new();
+        let mut args = Vec::new();
+        for (index, inj) in template.template_steps.injs.iter().enumerate() {
+            let arg = match inj.kind {
+                StepKind::AttrMapInjection => format!("attrs_{}", index),
+                _ => format!("descendants_{}", index),
+            };
+            params.push(format!("{}: coyotes::Component", arg));
+            args.push(arg);
+        }
+
+        module.push_str(&format!(
+            "\npub fn {}({}) -> coyotes::Component {{\n    coyotes::tmpl({}, [{}])\n}}\n",
+            template.fn_name,
+            params.join(", "),
+            template.const_name,
+            args.join(", ")
+        ));
+    }
+
+    module.push_str(
+        "\npub fn get_template_steps() -> Vec<(&'static str, coyotes::TemplateSteps)> {\n    Vec::from([",
+    );
+    for template in compiled {
+        module.push_str(&format!(
+            "\n        (\n            {},\n            coyotes::TemplateSteps {{\n                steps: Vec::from([",
+            template.const_name
+        ));
+        for steps in &template.template_steps.steps {
+            module.push_str("\n                    Vec::from([");
+            push_steps_str(&mut module, steps, "                        ");
+            module.push_str("\n                    ]),");
+        }
+        module.push_str("\n                ]),\n                injs: Vec::from([");
+        push_steps_str(
+            &mut module,
+            &template.template_steps.injs,
+            "                    ",
+        );
+        module.push_str("\n                ]),\n            },\n        ),");
+    }
+    module.push_str("\n    ])\n}\n");
+
+    push_compiled_module_str(&mut module, compiled);
+
+    module
+}
+
+fn push_compiled_module_str(module: &mut String, compiled: &[CompiledTemplate]) {
+    module.push_str("\npub mod compiled {");
+    for template in compiled {
+        let mut chunks = String::from("");
+        for (index, steps) in template.template_steps.steps.iter().enumerate() {
+            push_chunk_str(&mut chunks, template, index, steps);
+        }
+
+        match chunks.is_empty() {
+            true => module.push_str(&format!(
+                "\n    pub fn {}(_writer: &mut coyotes::__private::StepWriter, _chunk: usize) -> Result<(), coyotes::Errors> {{\n        Ok(())\n    }}\n",
+                template.fn_name
+            )),
+            _ => module.push_str(&format!(
+                "\n    pub fn {}(writer: &mut coyotes::__private::StepWriter, chunk: usize) -> Result<(), coyotes::Errors> {{\n        match chunk {{{}\n            _ => {{}}\n        }}\n\n        Ok(())\n    }}\n",
+                template.fn_name, chunks
+            )),
+        }
+    }
+    module.push_str("}\n");
+
+    module.push_str(
+        "\npub fn get_compiled_steps() -> Vec<(&'static str, &'static str, coyotes::__private::CompiledSteps)> {\n    Vec::from([",
+    );
+    for template in compiled {
+        module.push_str(&format!(
+            "\n        (\n            {},\n            {:?},\n            compiled::{} as coyotes::__private::CompiledSteps,\n        ),",
+            template.const_name, template.rules_key, template.fn_name
+        ));
+    }
+    module.push_str("\n    ])\n}\n");
+}
+
+// chunks with pre-formatted results fall back to step writer calls
+// when the element they are injected within is not a plain element
+fn push_chunk_str(chunks: &mut String, template: &CompiledTemplate, index: usize, steps: &[Step]) {
+    let template_str = &template.template_str;
+
+    let mut calls = String::from("");
+    for step in steps {
+        push_step_call_str(&mut calls, template_str, step, "                ");
+    }
+
+    if calls.is_empty() {
+        return;
+    }
+
+    let chunk = match template.chunks.get(index) {
+        Some(chunk)
+            if chunk
+                .calls
+                .iter()
+                .any(|call| matches!(call, ChunkCall::Static(_, _))) =>
+        {
+            chunk
+        }
+        _ => {
+            chunks.push_str(&format!(
+                "\n            {} => {{{}\n            }}",
+                index, calls
+            ));
+            return;
+        }
+    };
+
+    let mut static_calls = String::from("");
+    let mut fallback_calls = String::from("");
+    for step in steps {
+        push_step_call_str(
+            &mut fallback_calls,
+            template_str,
+            step,
+            "                    ",
+        );
+    }
+
+    for call in &chunk.calls {
+        match call {
+            ChunkCall::Steps(start, end) => {
+                for step in &steps[*start..*end] {
+                    push_step_call_str(
+                        &mut static_calls,
+                        template_str,
+                        step,
+                        "                    ",
+                    );
+                }
+            }
+            ChunkCall::Static(segments, ops) => {
+                push_static_call_str(&mut static_calls, segments, ops)
+            }
+        }
+    }
+
+    chunks.push_str(&format!(
+        "\n            {} => match writer.get_static_frame({}) {{\n                Some(frame) => {{{}\n                }}\n                _ => {{{}\n                }}\n            }},",
+        index, chunk.depth, static_calls, fallback_calls
+    ));
+}
+
+fn push_static_call_str(calls: &mut String, segments: &[String], ops: &[StaticOp]) {
+    let indent = "\n                    ";
+    if !segments.is_empty() {
+        let segments = Vec::from_iter(segments.iter().map(|segment| format!("{:?}", segment)));
+        calls.push_str(&format!(
+            "{}writer.push_static(&frame, &[{}]);",
+            indent,
+            segments.join(", ")
+        ));
+    }
+
+    for op in ops {
+        let call = match op {
+            StaticOp::Truncate(len) => format!("writer.truncate_static(&frame, {});", len),
+            StaticOp::SetTextFormat(index, text_format) => format!(
+                "writer.set_static_text_format(&frame, {}, coyotes::__private::TextFormat::{:?});",
+                index, text_format
+            ),
+            StaticOp::PushElement(tag, text_format) => format!(
+                "writer.push_static_element({:?}, coyotes::__private::TextFormat::{:?});",
+                tag, text_format
+            ),
+        };
+        calls.push_str(indent);
+        calls.push_str(&call);
+    }
+}
+
+// a step writer call with the same results as compose_steps
+fn push_step_call_str(calls: &mut String, template_str: &str, step: &Step, indent: &str) {
+    let text = get_text_from_step(template_str, step);
+    let call = match step.kind {
+        StepKind::Tag => format!("writer.push_element({:?});", text),
+        StepKind::ElementClosed => "writer.close_element()".to_string(),
+        StepKind::EmptyElementClosed => "writer.close_empty_element()".to_string(),
+        StepKind::TailTag => format!("writer.pop_element({:?});", text),
+        StepKind::TailElementSpace => "writer.push_element_space();".to_string(),
+        StepKind::TailElementClosed => "writer.close_tail_tag();".to_string(),
+        StepKind::Text => format!("writer.push_text({:?});", text),
+        StepKind::TextAlt => format!("writer.push_alt_text({:?});", text),
+        StepKind::TextRcdata => format!("writer.push_text({:?});", text),
+        StepKind::TextLineSpace => format!("writer.push_text_line_space({:?});", text),
+        StepKind::TextSpace => format!("writer.push_text_space({:?});", text),
+        StepKind::Attr => format!("writer.push_attr({:?});", text),
+        StepKind::AttrValueSingleQuoted => {
+            format!("writer.push_attr_value_single_quoted({:?});", text)
+        }
+        StepKind::AttrValueDoubleQuoted => {
+            format!("writer.push_attr_value_double_quoted({:?});", text)
+        }
+        StepKind::AttrValueUnquoted => format!("writer.push_attr_value_unquoted({:?});", text),
+        StepKind::ElementSpace => "writer.push_element_space();".to_string(),
+        StepKind::ElementLineSpace => "writer.push_element_line_space();".to_string(),
+        _ => return,
+    };
+
+    // closing an element can fail on duplicate attributes
+    match call.ends_with(';') {
+        true => calls.push_str(&format!("\n{}{}", indent, call)),
+        _ => calls.push_str(&format!(
+            "\n{}if let Err(e) = {} {{\n{}    return Err(e);\n{}}}",
+            indent, call, indent, indent
+        )),
+    }
+}
+
+fn push_steps_str(module: &mut String, steps: &[Step], indent: &str) {
+    for step in steps {
+        module.push_str(&format!(
+            "\n{}coyotes::__private::Step {{ kind: coyotes::__private::StepKind::{:?}, origin: {}, target: {} }},",
+            indent, step.kind, step.origin, step.target
+        ));
+    }
+}
+
+// snake case identifier from a template name
+fn get_fn_name(name: &str) -> String {
+    let mut fn_name = String::from("");
+    for glyph in name.chars() {
+        match glyph.is_ascii_alphanumeric() {
+            true => fn_name.push(glyph.to_ascii_lowercase()),
+            _ => fn_name.push('_'),
+        }
+    }
+
+    if !fn_name.starts_with(|glyph: char| glyph.is_ascii_alphabetic()) {
+        fn_name.insert_str(0, "template_");
+    }
+
+    if RUST_KEYWORDS.contains(&fn_name.as_str())
+        || fn_name == "get_template_steps"
+        || fn_name == "get_compiled_steps"
+    {
+        fn_name.push('_');
+    }
+
+    fn_name
+}
+
+fn get_const_name(fn_name: &str) -> String {
+    format!("{}_TEMPLATE", fn_name.to_ascii_uppercase())
+}
+
+// Ruleset decisions a template is composed with. Compiled chunks
+// are only used by rulesets that make the same decisions.
+pub(crate) fn get_rules_key(rules: &dyn RulesetImpl, template_str: &str) -> String {
+    let template_steps = compose(rules, template_str);
+
+    let mut decisions = format!(
+        "{:?} {} {} {} {:?} {}",
+        template_steps,
+        rules.respect_indentation(),
+        rules.keep_comments(),
+        rules.get_initial_embedded_content(),
+        rules.get_duplicate_attr_policy(),
+        rules.attrs_are_case_sensitive(),
+    );
+
+    for step in template_steps.steps.iter().flatten() {
+        let text = get_text_from_step(template_str, step);
+        let decision = match step.kind {
+            StepKind::Tag => format!(
+                "{:?}",
+                (
+                    text,
+                    rules.tag_is_void_el(text),
+                    rules.tag_is_inline_el(text),
+                    rules.tag_is_banned_el(text),
+                    rules.tag_is_comment_el(text),
+                    rules.tag_is_embedded_content_el(text),
+                    rules.tag_is_preformatted_text_el(text),
+                    rules.tag_is_rcdata_el(text),
+                    rules.tag_is_template_el(text),
+                    rules.tag_is_prefix_of_contentless_el(text),
+                    rules.get_close_sequence_from_alt_text_tag(text),
+                    rules.get_close_sequence_from_contentless_tag(text),
+                )
+            ),
+            StepKind::TailTag => format!(
+                "{:?}",
+                (
+                    text,
+                    rules.get_alt_text_tag_from_close_sequence(text),
+                    rules.get_contentless_tag_from_close_sequence(text),
+                )
+            ),
+            StepKind::Attr => format!("{:?}", (text, rules.attr_is_mergeable(text.trim()))),
+            _ => continue,
+        };
+
+        decisions.push(' ');
+        decisions.push_str(&decision);
+    }
+
+    format!("{:08x}", get_hash("rules", &decisions))
+}
+
+fn get_compiled_chunks(
+    rules: &dyn RulesetImpl,
+    template_str: &str,
+    template_steps: &TemplateSteps,
+) -> Vec<CompiledChunk> {
+    let mut variants = Vec::new();
+    for indent in INDENTS {
+        for text_format in TEXT_FORMATS {
+            let mut tag_info = TagInfo::get_root(rules);
+            tag_info.indent_count = indent;
+            tag_info.text_format = text_format;
+
+            variants.push(Variant {
+                indent,
+                results: String::from(""),
+                stack: Vec::from([tag_info]),
+            });
+        }
+    }
+
+    let mut chunks = Vec::new();
+    let mut composable = true;
+    let mut in_tag = false;
+
+    for (index, steps) in template_steps.steps.iter().enumerate() {
+        // any text format can precede the steps after an injection
+        if 0 < index && composable {
+            variants = get_injected_variants(&variants);
+        }
+
+        let depth = variants[0].stack.len() - 1;
+        if VARIANTS_LIMIT < variants.len()
+            || variants
+                .iter()
+                .any(|variant| variant.stack.len() != depth + 1)
+        {
+            composable = false;
+        }
+
+        let mut calls = Vec::new();
+        let mut run: Option<StaticRun> = None;
+
+        for (start, end, open) in get_units(steps, &mut in_tag) {
+            if !composable {
+                calls.push(ChunkCall::Steps(start, end));
+                continue;
+            }
+
+            let pre = get_stacks(&variants);
+            let unit = match compose_unit(rules, template_str, &mut variants, &steps[start..end]) {
+                Some(unit) => unit,
+                _ => {
+                    composable = false;
+                    push_static_run(&mut calls, rules, run.take(), &pre);
+                    calls.push(ChunkCall::Steps(start, end));
+                    continue;
+                }
+            };
+
+            let static_unit = match open {
+                true => None,
+                _ => get_static_unit(rules, &pre, &variants, &unit),
+            };
+
+            let (results, points, min_len) = match static_unit {
+                Some(static_unit) => static_unit,
+                _ => {
+                    push_static_run(&mut calls, rules, run.take(), &pre);
+                    calls.push(ChunkCall::Steps(start, end));
+                    continue;
+                }
+            };
+
+            match &mut run {
+                Some(run) => {
+                    let offset = run.results.len();
+                    run.points.extend(points.iter().map(|point| point + offset));
+                    run.results.push_str(&results);
+                    run.end = end;
+                    run.min_len = run.min_len.min(min_len);
+                }
+                _ => {
+                    run = Some(StaticRun {
+                        pre,
+                        start,
+                        end,
+                        results,
+                        points,
+                        min_len,
+                    })
+                }
+            }
+        }
+
+        push_static_run(&mut calls, rules, run.take(), &get_stacks(&variants));
+
+        chunks.push(CompiledChunk { depth, calls });
+    }
+
+    chunks
+}
+
+fn get_stacks(variants: &[Variant]) -> Vec<Vec<TagInfo>> {
+    Vec::from_iter(variants.iter().map(|variant| variant.stack.clone()))
+}
+
+fn get_injected_variants(variants: &[Variant]) -> Vec<Variant> {
+    let mut injected: Vec<Variant> = Vec::new();
+    for variant in variants {
+        for text_format in TEXT_FORMATS {
+            let mut next = variant.clone();
+            if let Some(tag_info) = next.stack.last_mut() {
+                tag_info.text_format = text_format;
+            }
+
+            if !injected
+                .iter()
+                .any(|prev| prev.indent == next.indent && prev.stack == next.stack)
+            {
+                injected.push(next);
+            }
+        }
+    }
+
+    injected
+}
+
+// Units are steps composed together, the steps of a tag are one unit.
+// Units of tags opened in a previous chunk or closed in a later chunk
+// are composed step by step.
+fn get_units(steps: &[Step], in_tag: &mut bool) -> Vec<(usize, usize, bool)> {
+    let mut units = Vec::new();
+    let mut start = 0;
+    let mut open = *in_tag;
+
+    for (index, step) in steps.iter().enumerate() {
+        match step.kind {
+            StepKind::Tag | StepKind::TailTag => *in_tag = true,
+            StepKind::ElementClosed
+            | StepKind::EmptyElementClosed
+            | StepKind::TailElementClosed => *in_tag = false,
+            _ => {}
+        }
+
+        if !*in_tag {
+            units.push((start, index + 1, open));
+            start = index + 1;
+            open = false;
+        }
+    }
+
+    if start < steps.len() {
+        units.push((start, steps.len(), true));
+    }
+
+    units
+}
+
+// Composes a unit within every variant and returns the results and the
+// shortest stack of each variant. Units that fail or close the element
+// a template is injected within leave later units to the step writer.
+fn compose_unit(
+    rules: &dyn RulesetImpl,
+    template_str: &str,
+    variants: &mut [Variant],
+    steps: &[Step],
+) -> Option<Vec<(String, usize)>> {
+    let mut unit = Vec::new();
+    for variant in variants.iter_mut() {
+        let origin = variant.results.len();
+        let mut min_len = variant.stack.len();
+
+        for step in steps {
+            let closes = matches!(
+                step.kind,
+                StepKind::TailTag | StepKind::ElementClosed | StepKind::EmptyElementClosed
+            );
+            if closes && variant.stack.len() < 2 {
+                return None;
+            }
+
+            let mut writer = StepWriter::new(rules, &mut variant.results, &mut variant.stack);
+            if compose_step(&mut writer, template_str, step).is_err() {
+                return None;
+            }
+
+            min_len = min_len.min(variant.stack.len());
+        }
+
+        unit.push((variant.results[origin..].to_string(), min_len));
+    }
+
+    Some(unit)
+}
+
+// Results of a unit that are the same within every variant apart from
+// tabs pushed at indent points. The first variant is never indented.
+fn get_static_unit(
+    rules: &dyn RulesetImpl,
+    pre: &[Vec<TagInfo>],
+    variants: &[Variant],
+    unit: &[(String, usize)],
+) -> Option<(String, Vec<usize>, usize)> {
+    let (results, min_len) = unit.first()?;
+
+    let mut points = Vec::new();
+    if let Some(index) = variants.iter().position(|variant| 1 == variant.indent) {
+        points = get_indent_points(results, &unit[index].0)?;
+    }
+
+    for (variant, (variant_results, variant_min_len)) in variants.iter().zip(unit) {
+        let segments = get_segments(results, &points)?;
+        if variant_min_len != min_len
+            || segments.join(&"\t".repeat(variant.indent)) != *variant_results
+        {
+            return None;
+        }
+    }
+
+    get_static_ops(rules, pre, &get_stacks(variants), *min_len)?;
+
+    Some((results.to_string(), points, *min_len))
+}
+
+// positions of results where tabs were pushed in indented results
+fn get_indent_points(results: &str, indented: &str) -> Option<Vec<usize>> {
+    let results = results.as_bytes();
+    let mut points = Vec::new();
+    let mut index = 0;
+
+    for byte in indented.bytes() {
+        if results.get(index) == Some(&byte) {
+            index += 1;
+            continue;
+        }
+
+        match byte {
+            b'\t' => points.push(index),
+            _ => return None,
+        }
+    }
+
+    match index == results.len() {
+        true => Some(points),
+        _ => None,
+    }
+}
+
+fn get_segments<'a>(results: &'a str, points: &[usize]) -> Option<Vec<&'a str>> {
+    let mut segments = Vec::new();
+    let mut origin = 0;
+    for point in points.iter().chain([&results.len()]) {
+        segments.push(results.get(origin..*point)?);
+        origin = *point;
+    }
+
+    Some(segments)
+}
+
+// Stack changes of a unit that are the same within every variant:
+// elements are popped, text formats converge, and plain elements are
+// pushed. Other changes depend on the element a template is injected
+// within.
+fn get_static_ops(
+    rules: &dyn RulesetImpl,
+    pre: &[Vec<TagInfo>],
+    post: &[Vec<TagInfo>],
+    min_len: usize,
+) -> Option<Vec<StaticOp>> {
+    let first = post.first()?;
+    if post.iter().any(|stack| stack.len() != first.len()) {
+        return None;
+    }
+
+    let mut ops = Vec::new();
+    if pre.iter().any(|stack| min_len < stack.len()) {
+        ops.push(StaticOp::Truncate(min_len));
+    }
+
+    for index in 0..min_len {
+        let mut unchanged = true;
+        for (pre_stack, post_stack) in pre.iter().zip(post) {
+            let mut tag_info = pre_stack[index].clone();
+            tag_info.text_format = post_stack[index].text_format.clone();
+            if tag_info != post_stack[index] {
+                return None;
+            }
+
+            unchanged = unchanged && pre_stack[index].text_format == tag_info.text_format;
+        }
+
+        let text_format = &first[index].text_format;
+        if unchanged {
+            continue;
+        }
+
+        if post
+            .iter()
+            .any(|stack| stack[index].text_format != *text_format)
+        {
+            return None;
+        }
+
+        ops.push(StaticOp::SetTextFormat(index, text_format.clone()));
+    }
+
+    for index in min_len.max(1)..first.len() {
+        let tag_info = &first[index];
+        for stack in post {
+            let mut next_tag_info = TagInfo::from(rules, &stack[index - 1], &tag_info.tag);
+            next_tag_info.text_format = tag_info.text_format.clone();
+            if next_tag_info != stack[index] {
+                return None;
+            }
+        }
+
+        ops.push(StaticOp::PushElement(
+            tag_info.tag.clone(),
+            tag_info.text_format.clone(),
+        ));
+    }
+
+    Some(ops)
+}
+
+fn push_static_run(
+    calls: &mut Vec<ChunkCall>,
+    rules: &dyn RulesetImpl,
+    run: Option<StaticRun>,
+    post: &[Vec<TagInfo>],
+) {
+    let run = match run {
+        Some(run) => run,
+        _ => return,
+    };
+
+    let static_call = match (
+        get_segments(&run.results, &run.points),
+        get_static_ops(rules, &run.pre, post, run.min_len),
+    ) {
+        (Some(segments), Some(ops)) => {
+            let segments = Vec::from_iter(
+                segments
+                    .iter()
+                    .filter(|_| !run.results.is_empty())
+                    .map(|segment| segment.to_string()),
+            );
+            ChunkCall::Static(segments, ops)
+        }
+        _ => ChunkCall::Steps(run.start, run.end),
+    };
+
+    // units that only space text push nothing
+    if let ChunkCall::Static(segments, ops) = &static_call
+        && segments.is_empty()
+        && ops.is_empty()
+    {
+        return;
+    }
+
+    calls.push(static_call);
+}
@@ -0,0 +1,218 @@
+use crate::components::Component;
+use std::collections::HashMap;
+use std::fs;
+use std::io;
+use std::path::{Path, PathBuf};
+use std::sync::{RwLock, RwLockReadGuard, RwLockWriteGuard};
+use std::time::SystemTime;
+
+/*
+    A registry of template files loaded from a directory.
+
+    Templates are named by their path relative to the directory
+    without the `.html` extension:
+    partials/nav.html -> "partials/nav"
+
+    In dev mode a template is read again when the modified time of
+    its file changes, and the directory is scanned again when a name
+    is missing so new files are found without a refresh.
+*/
+
+struct RegisteredTemplate {
+    path: PathBuf,
+    modified: Option<SystemTime>,
+    template_str: String,
+}
+
+pub struct TemplateRegistry {
+    dir: PathBuf,
+    dev_mode: bool,
+    templates: RwLock<HashMap<String, RegisteredTemplate>>,
+}
+
+impl TemplateRegistry {
+    pub fn load<P: AsRef<Path>>(dir: P) -> Result<TemplateRegistry, io::Error> {
+        let registry = TemplateRegistry {
+            dir: dir.as_ref().to_path_buf(),
+            dev_mode: false,
+            templates: RwLock::new(HashMap::new()),
+        };
+
+        registry.refresh()?;
+
+        Ok(registry)
+    }
+
+    pub fn set_dev_mode(&mut self, dev_mode: bool) {
+        self.dev_mode = dev_mode;
+    }
+
+    pub fn with_dev_mode(mut self, dev_mode: bool) -> TemplateRegistry {
+        self.set_dev_mode(dev_mode);
+        self
+    }
+
+    pub fn get<const N: usize>(&self, name: &str, injections: [Component; N]) -> Option<Component> {
+        self.get_template_str(name)
+            .map(|template_str| Component::TmplString(template_str, Vec::from(injections)))
+    }
+
+    pub fn get_template_str(&self, name: &str) -> Option<String> {
+        if self.dev_mode && !self.reload_if_modified(name) {
+            // a missing template might be a new file
+            let _ = self.refresh();
+        }
+
+        self.read_templates()
+            .get(name)
+            .map(|template| template.template_str.clone())
+    }
+
+    pub fn get_names(&self) -> Vec<String> {
+        if self.dev_mode {
+            let _ = self.refresh();
+        }
+
+        let mut names: Vec<String> = self.read_templates().keys().cloned().collect();
+        names.sort();
+
+        names
+    }
+
+    // read new and modified files, forget deleted files,
+    // returns the names of templates that were read
+    pub fn refresh(&self) -> Result<Vec<String>, io::Error> {
+        let paths = get_template_paths(&self.dir)?;
+        let mut templates = self.write_templates();
+
+        templates.retain(|name, _| paths.iter().any(|(path_name, _)| path_name == name));
+
+        let mut read_names = Vec::new();
+        for (name, path) in paths {
+            let modified = get_modified(&path);
+            if let Some(template) = templates.get(&name)
+                && template.modified.is_some()
+                && template.modified == modified
+            {
+                continue;
+            }
+
+            let template_str = fs::read_to_string(&path)?;
+
+            templates.insert(
+                name.clone(),
+                RegisteredTemplate {
+                    path,
+                    modified,
+                    template_str,
+                },
+            );
+            read_names.push(name);
+        }
+        read_names.sort();
+
+        Ok(read_names)
+    }
+
+    // returns false when the template is not registered
+    fn reload_if_modified(&self, name: &str) -> bool {
+        let path = match self.read_templates().get(name) {
+            Some(template) => match get_modified(&template.path) == template.modified {
+                true => return true,
+                _ => template.path.clone(),
+            },
+            _ => return false,
+        };
+
+        // keep the previous template if the file cannot be read
+        let template_str = match fs::read_to_string(&path) {
+            Ok(template_str) => template_str,
+            _ => return true,
+        };
+
+        self.write_templates().insert(
+            name.to_string(),
+            RegisteredTemplate {
+                modified: get_modified(&path),
+                path,
+                template_str,
+            },
+        );
+
+        true
+    }
+
+    // templates are plain data, a poisoned lock is recovered
+    fn read_templates(&self) -> RwLockReadGuard<'_, HashMap<String, RegisteredTemplate>> {
+        match self.templates.read() {
+            Ok(templates) => templates,
+            Err(poisoned) => poisoned.into_inner(),
+        }
+    }
+
+    fn write_templates(&self) -> RwLockWriteGuard<'_, HashMap<String, RegisteredTemplate>> {
+        match self.templates.write() {
+            Ok(templates) => templates,
+            Err(poisoned) => poisoned.into_inner(),
+        }
+    }
+}
+
+fn get_modified(path: &Path) -> Option<SystemTime> {
+    match fs::metadata(path) {
+        Ok(metadata) => metadata.modified().ok(),
+        _ => None,
+    }
+}
+
+// names and paths of every template in a directory sorted by name
+pub(crate) fn get_template_paths(dir: &Path) -> Result<Vec<(String, PathBuf)>, io::Error> {
+    let mut paths = Vec::new();
+    push_template_paths(&mut paths, dir, dir)?;
+    paths.sort();
+
+    Ok(paths)
+}
+
+fn push_template_paths(
+    paths: &mut Vec<(String, PathBuf)>,
+    root: &Path,
+    dir: &Path,
+) -> Result<(), io::Error> {
+    for entry in fs::read_dir(dir)? {
+        let path = entry?.path();
+
+        if path.is_dir() {
+            push_template_paths(paths, root, &path)?;
+            continue;
+        }
+
+        if Some("html") != path.extension().and_then(|extension| extension.to_str()) {
+            continue;
+        }
+
+        if let Some(name) = get_template_name(root, &path) {
+            paths.push((name, path));
+        }
+    }
+
+    Ok(())
+}
+
+// relative path without an extension and with forward slashes
+fn get_template_name(root: &Path, path: &Path) -> Option<String> {
+    let relative_path = match path.strip_prefix(root) {
+        Ok(relative_path) => relative_path.with_extension(""),
+        _ => return None,
+    };
+
+    let mut segments = Vec::new();
+    for segment in relative_path.iter() {
+        match segment.to_str() {
+            Some(segment) => segments.push(segment),
+            _ => return None,
+        }
+    }
+
+    Some(segments.join("/"))
+}
@@ -6,5 +6,6 @@ mod template_steps;
 
 pub use parse::{Step, get_text_from_step};
 pub use routes::StepKind;
-pub use rulesets::RulesetImpl;
+pub(crate) use rulesets::is_rcdata_el;
+pub use rulesets::{DuplicateAttrPolicy, RulesetImpl};
 pub use template_steps::{TemplateSteps, compose};
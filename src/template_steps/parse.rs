@@ -21,6 +21,7 @@ pub fn parse_str(rules: &dyn RulesetImpl, template_str: &str, intial_kind: StepK
     let mut tag: &str = "";
     let mut inj_kind = intial_kind;
     let mut sliding_window: Option<SlidingWindow> = None;
+    let mut rcdata_window: Option<SlidingWindow> = None;
     let mut contentless = false;
 
     for (index, glyph) in template_str.char_indices() {
@@ -50,6 +51,15 @@ pub fn parse_str(rules: &dyn RulesetImpl, template_str: &str, intial_kind: StepK
             continue;
         }
 
+        // <textarea>escapable raw text with {}</textarea>
+        if let Some(ref mut slider) = rcdata_window
+            && slider.slide(glyph)
+        {
+            push_alt_element_steps(rules, &mut steps, tag, index);
+            rcdata_window = None;
+            continue;
+        }
+
         // route next step
         let end_step = match steps.last_mut() {
             Some(step) => step,
@@ -72,8 +82,20 @@ pub fn parse_str(rules: &dyn RulesetImpl, template_str: &str, intial_kind: StepK
 
         match end_step.kind {
             StepKind::ElementClosed => {
+                // RCDATA ELEMENTS
+                if let (true, Some(close_seq)) = (
+                    rules.tag_is_rcdata_el(tag),
+                    rules.get_close_sequence_from_alt_text_tag(tag),
+                ) {
+                    let mut slider = SlidingWindow::new(close_seq);
+                    slider.slide(glyph);
+                    rcdata_window = Some(slider);
+                    match is_injection_kind(&curr_kind) {
+                        true => inj_kind = StepKind::TextRcdata,
+                        _ => curr_kind = StepKind::TextRcdata,
+                    }
                 // ALT ELEMENTS
-                if let Some(close_seq) = rules.get_close_sequence_from_alt_text_tag(tag) {
+                } else if let Some(close_seq) = rules.get_close_sequence_from_alt_text_tag(tag) {
                     let mut slider = SlidingWindow::new(close_seq);
                     slider.slide(glyph);
                     sliding_window = Some(slider);
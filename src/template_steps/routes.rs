@@ -30,6 +30,7 @@ pub enum StepKind {
     Text,
     TextAlt,
     TextLineSpace,
+    TextRcdata,
     TextSpace,
 }
 
@@ -58,6 +59,7 @@ pub fn route(glyph: char, prev_kind: &StepKind) -> StepKind {
         StepKind::TailElementSolidus => get_kind_from_tail_element_solidus(glyph),
         StepKind::TailElementSpace => get_kind_from_tail_element_space(glyph),
         StepKind::TailTag => get_kind_from_tail_tag(glyph),
+        StepKind::TextRcdata => get_kind_from_rcdata_text(glyph),
         _ => get_kind_from_text(glyph),
     }
 }
@@ -205,6 +207,14 @@ fn get_kind_from_tail_element_space(glyph: char) -> StepKind {
     }
 }
 
+// escapable raw text only leaves for injections and its close sequence
+fn get_kind_from_rcdata_text(glyph: char) -> StepKind {
+    match glyph {
+        '{' => StepKind::DescendantInjection,
+        _ => StepKind::TextRcdata,
+    }
+}
+
 fn get_kind_from_text(glyph: char) -> StepKind {
     match glyph {
         '<' => StepKind::Element,
@@ -1,3 +1,13 @@
+// how to resolve an attribute declared more than once on an element
+#[derive(Debug, Clone, Copy, Eq, PartialEq)]
+pub enum DuplicateAttrPolicy {
+    Error,
+    FirstWins,
+    LastWins,
+    Merge,
+}
+
+// methods with default implementations follow html behavior
 pub trait RulesetImpl {
     fn get_document_memory_limit(&self) -> usize;
     fn get_cache_memory_limit(&self) -> usize;
@@ -5,12 +15,67 @@ pub trait RulesetImpl {
     fn get_close_sequence_from_alt_text_tag(&self, tag: &str) -> Option<&str>;
     fn get_close_sequence_from_contentless_tag(&self, tag: &str) -> Option<&str>;
     fn get_contentless_tag_from_close_sequence(&self, tag: &str) -> Option<&str>;
+    fn get_duplicate_attr_policy(&self) -> DuplicateAttrPolicy {
+        DuplicateAttrPolicy::Merge
+    }
     fn get_initial_embedded_content(&self) -> &str;
+    fn keep_comments(&self) -> bool {
+        true
+    }
+    fn attr_is_mergeable(&self, attr: &str) -> bool {
+        is_mergeable_attr(attr)
+    }
+    // html attribute names are ascii case-insensitive
+    fn attrs_are_case_sensitive(&self) -> bool {
+        false
+    }
     fn respect_indentation(&self) -> bool;
     fn tag_is_banned_el(&self, tag: &str) -> bool;
+    fn tag_is_comment_el(&self, tag: &str) -> bool {
+        is_comment_el(tag)
+    }
     fn tag_is_inline_el(&self, tag: &str) -> bool;
     fn tag_is_embedded_content_el(&self, tag: &str) -> bool;
     fn tag_is_prefix_of_contentless_el(&self, tag: &str) -> Option<&str>;
     fn tag_is_preformatted_text_el(&self, tag: &str) -> bool;
+    // rcdata elements need an alt text close sequence
+    fn tag_is_rcdata_el(&self, tag: &str) -> bool {
+        is_rcdata_el(tag) && self.get_close_sequence_from_alt_text_tag(tag).is_some()
+    }
+    fn tag_is_template_el(&self, tag: &str) -> bool {
+        is_template_el(tag)
+    }
     fn tag_is_void_el(&self, tag: &str) -> bool;
 }
+
+fn is_comment_el(tag: &str) -> bool {
+    tag == "!--"
+}
+
+// elements with content parsed as a separate document fragment
+fn is_template_el(tag: &str) -> bool {
+    tag == "template"
+}
+
+// escapable raw text, content is text and whitespace is kept
+pub fn is_rcdata_el(tag: &str) -> bool {
+    matches!(tag, "textarea" | "title")
+}
+
+// attributes with values that merge like token lists and declarations
+fn is_mergeable_attr(attr: &str) -> bool {
+    matches!(
+        attr,
+        "accesskey"
+            | "class"
+            | "headers"
+            | "itemprop"
+            | "itemref"
+            | "itemtype"
+            | "part"
+            | "ping"
+            | "rel"
+            | "sandbox"
+            | "style"
+    )
+}
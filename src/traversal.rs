@@ -0,0 +1,156 @@
+use crate::components::Component;
+use std::sync::Arc;
+
+/*
+    Utilities to inspect and transform component trees before rendering.
+
+    Traversals descend into lists, template injections, shared components,
+    and memo components in document order.
+
+    Transformations rebuild shared components with new references.
+*/
+
+// visit every component in document order, parents before descendants
+pub fn walk<F: FnMut(&Component)>(component: &Component, mut visit: F) {
+    walk_component(component, &mut visit);
+}
+
+pub fn find_all<F: FnMut(&Component) -> bool>(
+    component: &Component,
+    mut predicate: F,
+) -> Vec<&Component> {
+    let mut found = Vec::new();
+    find_components(component, &mut predicate, &mut found);
+    found
+}
+
+// transform every component, descendants before parents
+pub fn map_components<F: FnMut(Component) -> Component>(
+    component: Component,
+    mut transform: F,
+) -> Component {
+    map_component(component, &mut transform)
+}
+
+pub fn map_text<F: FnMut(&str) -> String>(component: Component, mut transform: F) -> Component {
+    map_components(component, |cmpnt| match cmpnt {
+        Component::Text(text) => Component::Text(transform(&text)),
+        _ => cmpnt,
+    })
+}
+
+// transform attribute components: attr, attr_val, attr_url, classes, and styles
+pub fn map_attrs<F: FnMut(Component) -> Component>(
+    component: Component,
+    mut transform: F,
+) -> Component {
+    map_components(component, |cmpnt| match is_attr_component(&cmpnt) {
+        true => transform(cmpnt),
+        _ => cmpnt,
+    })
+}
+
+pub fn is_attr_component(component: &Component) -> bool {
+    matches!(
+        component,
+        Component::Attr(_)
+            | Component::AttrVal(_, _)
+            | Component::AttrUrl(_, _)
+            | Component::Classes(_)
+            | Component::Styles(_)
+    )
+}
+
+// descendants of a component in document order
+pub fn get_descendants(component: &Component) -> Vec<&Component> {
+    match component {
+        Component::List(list) => list.iter().collect(),
+        Component::Tmpl(_, injections) => injections.iter().collect(),
+        Component::TmplString(_, injections) => injections.iter().collect(),
+        Component::Shared(shared) => Vec::from([shared.as_ref()]),
+        Component::Memo(_, memo_cmpnt) => Vec::from([memo_cmpnt.as_ref()]),
+        Component::Portal(_, _, portal_cmpnt) => Vec::from([portal_cmpnt.as_ref()]),
+        Component::Scoped(_, scoped_cmpnt) => Vec::from([scoped_cmpnt.as_ref()]),
+        Component::Island(_, _, island_cmpnt) => Vec::from([island_cmpnt.as_ref()]),
+        Component::Boundary(cmpnt, fallback) => Vec::from([cmpnt.as_ref(), fallback.as_ref()]),
+        Component::WithContext(_, context_cmpnt) => Vec::from([context_cmpnt.as_ref()]),
+        _ => Vec::new(),
+    }
+}
+
+fn walk_component<F: FnMut(&Component)>(component: &Component, visit: &mut F) {
+    visit(component);
+
+    for descendant in get_descendants(component) {
+        walk_component(descendant, visit);
+    }
+}
+
+fn find_components<'a, F: FnMut(&Component) -> bool>(
+    component: &'a Component,
+    predicate: &mut F,
+    found: &mut Vec<&'a Component>,
+) {
+    if predicate(component) {
+        found.push(component);
+    }
+
+    for descendant in get_descendants(component) {
+        find_components(descendant, predicate, found);
+    }
+}
+
+fn map_component<F: FnMut(Component) -> Component>(
+    component: Component,
+    transform: &mut F,
+) -> Component {
+    let mapped = match component {
+        Component::List(list) => Component::List(map_vec(list, transform)),
+        Component::Tmpl(template, injections) => {
+            Component::Tmpl(template, map_vec(injections, transform))
+        }
+        Component::TmplString(template_str, injections) => {
+            Component::TmplString(template_str, map_vec(injections, transform))
+        }
+        Component::Shared(shared) => {
+            let cmpnt = shared.as_ref().clone();
+            Component::Shared(Arc::new(map_component(cmpnt, transform)))
+        }
+        Component::Memo(key, memo_cmpnt) => {
+            Component::Memo(key, Box::new(map_component(*memo_cmpnt, transform)))
+        }
+        Component::Portal(slot, key, portal_cmpnt) => {
+            Component::Portal(slot, key, Box::new(map_component(*portal_cmpnt, transform)))
+        }
+        Component::Scoped(style, scoped_cmpnt) => {
+            Component::Scoped(style, Box::new(map_component(*scoped_cmpnt, transform)))
+        }
+        Component::Island(name, props, island_cmpnt) => Component::Island(
+            name,
+            props,
+            Box::new(map_component(*island_cmpnt, transform)),
+        ),
+        Component::Boundary(cmpnt, fallback) => Component::Boundary(
+            Box::new(map_component(*cmpnt, transform)),
+            Box::new(map_component(*fallback, transform)),
+        ),
+        Component::WithContext(context, context_cmpnt) => {
+            Component::WithContext(context, Box::new(map_component(*context_cmpnt, transform)))
+        }
+        _ => component,
+    };
+
+    transform(mapped)
+}
+
+fn map_vec<F: FnMut(Component) -> Component>(
+    components: Vec<Component>,
+    transform: &mut F,
+) -> Vec<Component> {
+    let mut mapped = Vec::new();
+    for component in components {
+        mapped.push(map_component(component, transform));
+    }
+
+    mapped
+}
@@ -0,0 +1,113 @@
+use coyotes::{
+    AssetManifest, Component, Html, HtmlOnly, RenderContext, list, module_script, script, slot,
+    stylesheet, text, tmpl,
+};
+
+fn document(body: Component) -> Component {
+    tmpl(
+        "
+        <html>
+            <head>
+                {}
+            </head>
+            <body>
+                {}
+                {}
+            </body>
+        </html>
+        ",
+        [slot("head"), body, slot("body")],
+    )
+}
+
+fn carousel() -> Component {
+    list([
+        stylesheet("/carousel.css"),
+        script("/carousel.js"),
+        tmpl("<section>carousel</section>", []),
+    ])
+}
+
+fn chart() -> Component {
+    list([
+        stylesheet("/chart.css"),
+        module_script("/chart.js"),
+        tmpl("<figure>chart</figure>", []),
+    ])
+}
+
+#[test]
+fn assets_render_once_at_slots() {
+    let template = document(list([carousel(), chart(), carousel()]));
+    let expected = "<html>\n\t<head>\n\t\t<link rel=\"stylesheet\" href=\"/carousel.css\">\n\t\t<link rel=\"stylesheet\" href=\"/chart.css\">\n\t</head>\n\t<body>\n\t\t<section>carousel</section><figure>chart</figure><section>carousel</section>\n\t\t<script src=\"/carousel.js\"></script>\n\t\t<script type=\"module\" src=\"/chart.js\"></script>\n\t</body>\n</html>";
+
+    let mut html = Html::new();
+    let results = html.render(&template);
+
+    assert_eq!(Ok(expected.to_string()), results);
+}
+
+#[test]
+fn assets_use_fingerprinted_urls() {
+    let template = document(carousel());
+    let expected = "<html>\n\t<head>\n\t\t<link rel=\"stylesheet\" href=\"/carousel.3f9a2c.css\">\n\t</head>\n\t<body>\n\t\t<section>carousel</section>\n\t\t<script src=\"/carousel.js\"></script>\n\t</body>\n</html>";
+
+    let manifest = AssetManifest::new().with("/carousel.css", "/carousel.3f9a2c.css");
+    let context = RenderContext::new().with(manifest);
+
+    let mut html = Html::new();
+    let results = html.render_with_context(&template, &context);
+
+    assert_eq!(Ok(expected.to_string()), results);
+}
+
+#[test]
+fn assets_are_removed_by_html_only() {
+    let template = tmpl(
+        "<div>{}</div><footer>{}</footer>",
+        [list([script("/uwu.js"), text("hai :3")]), slot("body")],
+    );
+    let expected = "<div>hai :3</div><footer></footer>";
+
+    let mut html = HtmlOnly::new();
+    let results = html.render(&template);
+
+    assert_eq!(Ok(expected.to_string()), results);
+}
+
+#[test]
+fn scripts_and_module_scripts_of_a_url_render_once_each() {
+    let template = document(list([
+        script("/app.js"),
+        module_script("/app.js"),
+        script("/app.js"),
+    ]));
+    let expected = "<html>\n\t<head>\n\t</head>\n\t<body>\n\t\t<script src=\"/app.js\"></script>\n\t\t<script type=\"module\" src=\"/app.js\"></script>\n\t</body>\n</html>";
+
+    let mut html = Html::new();
+    let results = html.render(&template);
+
+    assert_eq!(Ok(expected.to_string()), results);
+}
+
+#[test]
+fn stylesheets_and_scripts_of_a_url_render_once_each() {
+    let template = document(list([stylesheet("/app"), script("/app")]));
+    let expected = "<html>\n\t<head>\n\t\t<link rel=\"stylesheet\" href=\"/app\">\n\t</head>\n\t<body>\n\t\t<script src=\"/app\"></script>\n\t</body>\n</html>";
+
+    let mut html = Html::new();
+    let results = html.render(&template);
+
+    assert_eq!(Ok(expected.to_string()), results);
+}
+
+#[test]
+fn stylesheets_and_module_scripts_of_a_url_render_once_each() {
+    let template = document(list([stylesheet("/app"), module_script("/app")]));
+    let expected = "<html>\n\t<head>\n\t\t<link rel=\"stylesheet\" href=\"/app\">\n\t</head>\n\t<body>\n\t\t<script type=\"module\" src=\"/app\"></script>\n\t</body>\n</html>";
+
+    let mut html = Html::new();
+    let results = html.render(&template);
+
+    assert_eq!(Ok(expected.to_string()), results);
+}
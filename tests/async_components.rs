@@ -0,0 +1,271 @@
+#![cfg(feature = "async")]
+
+use coyotes::{
+    Component, ErrorHook, Errors, Html, RenderContext, attr_val, boundary, compose_string_async,
+    future, lazy, list, text, tmpl,
+};
+use std::future::Future;
+use std::panic::{AssertUnwindSafe, catch_unwind};
+use std::pin::pin;
+use std::sync::{Arc, Mutex};
+use std::task::{Context, Poll, Waker};
+
+// a minimal executor to drive futures in tests
+fn block_on<F: Future>(future: F) -> F::Output {
+    let mut future = pin!(future);
+    let mut cx = Context::from_waker(Waker::noop());
+
+    loop {
+        if let Poll::Ready(output) = future.as_mut().poll(&mut cx) {
+            return output;
+        }
+    }
+}
+
+// a future that is pending a number of times before it is ready
+struct Yield {
+    count: usize,
+}
+
+impl Future for Yield {
+    type Output = ();
+
+    fn poll(mut self: std::pin::Pin<&mut Self>, _cx: &mut Context<'_>) -> Poll<()> {
+        match self.count {
+            0 => Poll::Ready(()),
+            _ => {
+                self.count -= 1;
+                Poll::Pending
+            }
+        }
+    }
+}
+
+fn fetch_name(name: &'static str, delay: usize, log: Arc<Mutex<Vec<String>>>) -> Component {
+    future(async move {
+        Yield { count: delay }.await;
+        if let Ok(mut log) = log.lock() {
+            log.push(name.to_string());
+        }
+
+        text(name)
+    })
+}
+
+#[test]
+fn async_components_render_in_document_order() {
+    let log = Arc::new(Mutex::new(Vec::new()));
+    let template = tmpl(
+        "<p>{}</p><p>{}</p>",
+        [
+            fetch_name("coyote", 3, log.clone()),
+            fetch_name("wolf", 0, log.clone()),
+        ],
+    );
+    let expected = "<p>coyote</p><p>wolf</p>";
+
+    let mut html = Html::new();
+    let results = block_on(html.render_async(&template));
+
+    assert_eq!(Ok(expected.to_string()), results);
+    assert_eq!(
+        Vec::from(["coyote".to_string(), "wolf".to_string()]),
+        *log.lock().unwrap()
+    );
+}
+
+#[test]
+fn concurrent_async_components_preserve_document_order() {
+    let log = Arc::new(Mutex::new(Vec::new()));
+    let template = tmpl(
+        "<p>{}</p><p>{}</p>",
+        [
+            fetch_name("coyote", 3, log.clone()),
+            fetch_name("wolf", 0, log.clone()),
+        ],
+    );
+    let expected = "<p>coyote</p><p>wolf</p>";
+
+    let mut html = Html::new();
+    let results = block_on(html.render_async_concurrently(&template));
+
+    assert_eq!(Ok(expected.to_string()), results);
+    assert_eq!(
+        Vec::from(["wolf".to_string(), "coyote".to_string()]),
+        *log.lock().unwrap()
+    );
+}
+
+#[test]
+fn nested_async_components_are_resolved() {
+    let log = Arc::new(Mutex::new(Vec::new()));
+    let nested_log = log.clone();
+    let template = tmpl(
+        "<ul>{}</ul>",
+        [future(async move {
+            list([
+                tmpl("<li>{}</li>", [fetch_name("coyote", 1, nested_log.clone())]),
+                tmpl("<li>{}</li>", [fetch_name("wolf", 2, nested_log)]),
+            ])
+        })],
+    );
+    let expected = "<ul><li>coyote</li><li>wolf</li></ul>";
+
+    let mut html = Html::new();
+    let results = block_on(html.render_async_concurrently(&template));
+
+    assert_eq!(Ok(expected.to_string()), results);
+}
+
+#[test]
+fn async_attribute_components_are_resolved() {
+    let template = tmpl(
+        "<img {}>",
+        [future(async { attr_val("src", "/coyote.png") })],
+    );
+    let expected = "<img src=\"/coyote.png\">";
+
+    let mut html = Html::new();
+    let results = block_on(html.render_async(&template));
+
+    assert_eq!(Ok(expected.to_string()), results);
+}
+
+#[test]
+fn unresolved_async_components_return_errors() {
+    let template = tmpl("<p>{}</p>", [future(async { text("hai :3") })]);
+
+    let mut html = Html::new();
+    let results = html.render(&template);
+
+    assert_eq!(Err(Errors::UnresolvedAsyncComponent), results);
+}
+
+#[test]
+fn renders_share_pending_async_components() {
+    let log = Arc::new(Mutex::new(Vec::new()));
+    let template = tmpl("<p>{}</p>", [fetch_name("coyote", 3, log.clone())]);
+    let expected = "<p>coyote</p>";
+
+    let mut first_html = Html::new();
+    let mut first = pin!(first_html.render_async(&template));
+    let mut cx = Context::from_waker(Waker::noop());
+    assert!(first.as_mut().poll(&mut cx).is_pending());
+
+    // the second render awaits the future the first render is polling
+    let mut second_html = Html::new();
+    let second = block_on(second_html.render_async(&template));
+
+    assert_eq!(Ok(expected.to_string()), second);
+    assert_eq!(Ok(expected.to_string()), block_on(first));
+    assert_eq!(Vec::from(["coyote".to_string()]), *log.lock().unwrap());
+}
+
+#[test]
+fn async_components_from_lazy_components_are_resolved() {
+    let template = tmpl(
+        "<p>{}</p>",
+        [lazy(|context| {
+            let name = match context.get::<&str>() {
+                Some(name) => name.to_string(),
+                _ => "nobody".to_string(),
+            };
+
+            future(async move { text(&name) })
+        })],
+    );
+    let expected = "<p>coyote</p>";
+
+    let mut html = Html::new();
+    let context = RenderContext::new().with("coyote");
+    let results = block_on(compose_string_async(
+        html.get_builder_mut(),
+        &coyotes::HtmlRules::new(),
+        &template,
+        &context,
+    ));
+
+    assert_eq!(Ok(expected.to_string()), results);
+}
+
+#[test]
+fn errors_are_reported_once_per_async_render() {
+    let reports = Arc::new(Mutex::new(0));
+    let hook_reports = reports.clone();
+    let template = tmpl(
+        "{}{}",
+        [
+            boundary(tmpl("<p>", []), text("fallback")),
+            future(async { text("hai :3") }),
+        ],
+    );
+    let expected = "fallbackhai :3";
+
+    let mut html = Html::new();
+    let context = RenderContext::new().with(ErrorHook::new(move |_| {
+        *hook_reports.lock().unwrap() += 1;
+    }));
+    let results = block_on(compose_string_async(
+        html.get_builder_mut(),
+        &coyotes::HtmlRules::new(),
+        &template,
+        &context,
+    ));
+
+    assert_eq!(Ok(expected.to_string()), results);
+    assert_eq!(1, *reports.lock().unwrap());
+}
+
+#[test]
+fn async_renders_are_send() {
+    fn assert_send<T: Send>(_: T) {}
+
+    let template = tmpl("<p>{}</p>", [future(async { text("hai :3") })]);
+    let mut html = Html::new();
+    assert_send(html.render_async(&template));
+}
+
+#[test]
+fn panicked_async_components_fail_later_renders() {
+    let template = tmpl(
+        "<p>{}</p>",
+        [future(async {
+            panic!("no coyotes");
+        })],
+    );
+
+    let first = catch_unwind(AssertUnwindSafe(|| {
+        block_on(Html::new().render_async(&template))
+    }));
+    assert!(first.is_err());
+
+    let mut html = Html::new();
+    let second = block_on(html.render_async(&template));
+    let third = block_on(html.render_async(&template));
+
+    assert_eq!(Err(Errors::AsyncComponentFailed), second);
+    assert_eq!(Err(Errors::AsyncComponentFailed), third);
+    assert_eq!(Err(Errors::AsyncComponentFailed), html.render(&template));
+}
+
+#[test]
+fn panicked_async_components_render_boundary_fallbacks() {
+    let failed = future(async {
+        panic!("no coyotes");
+    });
+    let template = tmpl(
+        "<p>{}</p>",
+        [boundary(failed.clone(), text("no coyotes :("))],
+    );
+    let expected = "<p>no coyotes :(</p>";
+
+    let first = catch_unwind(AssertUnwindSafe(|| {
+        block_on(Html::new().render_async(&tmpl("<p>{}</p>", [failed])))
+    }));
+    assert!(first.is_err());
+
+    let mut html = Html::new();
+    let results = block_on(html.render_async(&template));
+
+    assert_eq!(Ok(expected.to_string()), results);
+}
@@ -0,0 +1,97 @@
+use coyotes::{Html, attr_val, class_if, classes, list, styles, tmpl};
+
+#[test]
+fn classes_render_as_class_attribute() {
+    let template = tmpl("<p {}></p>", [classes(["card", "card--active", "card"])]);
+    let expected = "<p class=\"card card--active\"></p>";
+
+    let mut html = Html::new();
+    let results = html.render(&template);
+
+    assert_eq!(Ok(expected.to_string()), results);
+}
+
+#[test]
+fn class_if_renders_only_when_true() {
+    let template = tmpl(
+        "<p {}></p><p {}></p>",
+        [class_if(true, "active"), class_if(false, "active")],
+    );
+    let expected = "<p class=\"active\"></p><p></p>";
+
+    let mut html = Html::new();
+    let results = html.render(&template);
+
+    assert_eq!(Ok(expected.to_string()), results);
+}
+
+#[test]
+fn classes_merge_with_template_class() {
+    let template = tmpl(
+        "<p class=\"card large\" {}></p>",
+        [list([classes(["card", "dark"]), class_if(true, "active")])],
+    );
+    let expected = "<p class=\"card large dark active\"></p>";
+
+    let mut html = Html::new();
+    let results = html.render(&template);
+
+    assert_eq!(Ok(expected.to_string()), results);
+}
+
+#[test]
+fn template_class_merges_into_injected_classes() {
+    let template = tmpl("<input {} class='wide'>", [classes(["field"])]);
+    let expected = "<input class=\"field wide\">";
+
+    let mut html = Html::new();
+    let results = html.render(&template);
+
+    assert_eq!(Ok(expected.to_string()), results);
+}
+
+#[test]
+fn styles_merge_with_template_style() {
+    let template = tmpl(
+        "<p style=\"color: blue;margin:0\" id=hai {}></p>",
+        [styles([("color", "red"), ("padding", "1em")])],
+    );
+    let expected = "<p style=\"color: red; margin: 0; padding: 1em\" id=hai></p>";
+
+    let mut html = Html::new();
+    let results = html.render(&template);
+
+    assert_eq!(Ok(expected.to_string()), results);
+}
+
+#[test]
+fn class_attribute_values_merge() {
+    let template = tmpl(
+        "<p {}></p>",
+        [list([attr_val("class", "a b"), classes(["b", "c"])])],
+    );
+    let expected = "<p class=\"a b c\"></p>";
+
+    let mut html = Html::new();
+    let results = html.render(&template);
+
+    assert_eq!(Ok(expected.to_string()), results);
+}
+
+#[test]
+fn classes_and_styles_are_escaped() {
+    let template = tmpl(
+        "<p {}></p>",
+        [list([
+            classes(["\"><script>"]),
+            styles([("font-family", "\"Comic Sans\"")]),
+        ])],
+    );
+    let expected =
+        "<p class=\"&quot;><script>\" style=\"font-family: &quot;Comic Sans&quot;\"></p>";
+
+    let mut html = Html::new();
+    let results = html.render(&template);
+
+    assert_eq!(Ok(expected.to_string()), results);
+}
@@ -0,0 +1,65 @@
+use coyotes::{Component, DocumentParams, Html, Xml, comment, list, text, tmpl};
+
+fn page(content: Component) -> Component {
+    tmpl(
+        "
+        <main>
+            <!-- page -->
+            {}
+        </main>
+        ",
+        [content],
+    )
+}
+
+#[test]
+fn comment_component_renders() {
+    let template = page(comment(" build 1234 "));
+    let expected = "<main>\n\t<!-- page -->\n\t<!-- build 1234 -->\n</main>";
+
+    let mut html = Html::new();
+    let results = html.render(&template);
+
+    assert_eq!(Ok(expected.to_string()), results);
+}
+
+#[test]
+fn comment_component_cannot_close_early() {
+    let template = list([
+        comment("--><script>alert('uwu')</script><!--"),
+        comment("-> ---!> <!-"),
+    ]);
+    let expected = "<!--- -&gt;&lt;script&gt;alert('uwu')&lt;/script&gt;&lt;!- - --><!---&gt; - - -!&gt; &lt;!- -->";
+
+    let mut html = Html::new();
+    let results = html.render(&template);
+
+    assert_eq!(Ok(expected.to_string()), results);
+}
+
+#[test]
+fn comments_are_stripped_by_ruleset() {
+    let template = page(list([comment(" build 1234 "), text("hai :3")]));
+    let expected = "<main>\n\thai :3\n</main>";
+
+    let params = DocumentParams {
+        keep_comments: false,
+        ..Default::default()
+    };
+
+    let mut html = Html::from(&params);
+    let results = html.render(&template);
+
+    assert_eq!(Ok(expected.to_string()), results);
+}
+
+#[test]
+fn xml_comment_component_renders() {
+    let template = tmpl("<message>{}</message>", [comment("hai -- :3")]);
+    let expected = "<message><!--hai - - :3--></message>";
+
+    let mut xml = Xml::new();
+    let results = xml.render(&template);
+
+    assert_eq!(Ok(expected.to_string()), results);
+}
@@ -0,0 +1,159 @@
+use coyotes::{
+    Component, DefaultBuilder, DocumentParams, DuplicateAttrPolicy, Errors, Html, HtmlRules,
+    ScopedStyle, Xml, XmlRules, attr, get_template_module, list, scoped, text, tmpl,
+};
+use std::fs;
+
+// generated from tests/templates/compiled with HtmlRules
+mod templates {
+    include!("generated/templates.rs");
+}
+
+fn get_templates() -> Vec<Component> {
+    Vec::from([
+        templates::greeting(attr("hidden"), text("coyote")),
+        templates::card(
+            text("howls"),
+            tmpl("<ul>\n\t<li>{}</li>\n</ul>", [text("awoo")]),
+        ),
+        templates::inline(text("hai"), tmpl("<em>{}</em>", [text(":3")])),
+        templates::page(
+            text("coyotes"),
+            attr("class"),
+            templates::greeting(Component::None, text("there")),
+        ),
+        templates::raw_text(text("howl")),
+    ])
+}
+
+// templates injected within elements with every kind of formatting
+fn get_contexts(template: &Component) -> Vec<Component> {
+    let style = ScopedStyle::new("card", ".card { color: red; }");
+
+    Vec::from([
+        template.clone(),
+        tmpl("<main>{}</main>", [template.clone()]),
+        tmpl("hai {} :3", [template.clone()]),
+        tmpl(
+            "<body>\n\t<main>\n\t\t{}\n\t</main>\n</body>",
+            [template.clone()],
+        ),
+        tmpl(
+            "<section>\n\t<div {}>\n\t\t<span>{}</span>\n\t</div>\n</section>",
+            [attr("hidden"), template.clone()],
+        ),
+        tmpl("<p>\n\t{}\n\t</p>", [template.clone()]),
+        tmpl("<pre>\n\t{}\n</pre>", [template.clone()]),
+        tmpl("<svg>\n\t{}\n</svg>", [template.clone()]),
+        list([template.clone(), text("hai"), template.clone()]),
+        scoped(&style, tmpl("<div>\n\t{}\n</div>", [template.clone()])),
+    ])
+}
+
+#[test]
+fn generated_module_is_up_to_date() {
+    let module = get_template_module(&HtmlRules::new(), "tests/templates/compiled").unwrap();
+    let expected = fs::read_to_string("tests/generated/templates.rs").unwrap();
+
+    assert_eq!(expected, module);
+}
+
+#[test]
+fn generated_module_pushes_static_results() {
+    let module = fs::read_to_string("tests/generated/templates.rs").unwrap();
+
+    assert!(module.contains("writer.push_static(&frame, &[\"\\n\\t\", \"<p>hai\"]);"));
+    assert!(
+        module
+            .contains("writer.push_static_element(\"p\", coyotes::__private::TextFormat::Space);")
+    );
+}
+
+#[test]
+fn compiled_steps_match_template_steps() {
+    let mut html = Html::new();
+    let mut compiled_html = Html::new();
+    compiled_html.prepare_steps(&templates::get_template_steps());
+    compiled_html.prepare_compiled_steps(&templates::get_compiled_steps());
+
+    for template in get_templates() {
+        for component in get_contexts(&template) {
+            let expected = html.render(&component);
+            let results = compiled_html.render(&component);
+
+            assert_eq!(expected, results);
+        }
+    }
+}
+
+#[test]
+fn compiled_steps_match_template_steps_with_xml() {
+    let mut xml = Xml::new();
+    let mut compiled_xml = Xml::new();
+    compiled_xml.prepare_compiled_steps(&templates::get_compiled_steps());
+
+    for template in get_templates() {
+        for component in get_contexts(&template) {
+            let expected = xml.render(&component);
+            let results = compiled_xml.render(&component);
+
+            assert_eq!(expected, results);
+        }
+    }
+}
+
+#[test]
+fn compiled_steps_are_keyed_by_ruleset() {
+    let mut html_builder = DefaultBuilder::new();
+    let mut xml_builder = DefaultBuilder::new();
+
+    for (template_str, rules_key, compiled) in templates::get_compiled_steps() {
+        assert!(html_builder.insert_compiled_steps(
+            &HtmlRules::new(),
+            template_str,
+            rules_key,
+            compiled
+        ));
+        assert!(!xml_builder.insert_compiled_steps(
+            &XmlRules::new(),
+            template_str,
+            rules_key,
+            compiled
+        ));
+    }
+}
+
+#[test]
+fn compiled_steps_render_nested_templates() {
+    let template = tmpl(
+        "<main>\n\t{}\n</main>",
+        [templates::greeting(attr("hidden"), text("coyote"))],
+    );
+    let expected = "<main>
+	<article hidden>
+		<p>hai coyote :3</p>
+	</article>
+</main>";
+
+    let mut html = Html::new();
+    html.prepare_compiled_steps(&templates::get_compiled_steps());
+    let results = html.render(&template);
+
+    assert_eq!(Ok(expected.to_string()), results);
+}
+
+#[test]
+fn compiled_steps_report_duplicate_attributes() {
+    let params = DocumentParams {
+        duplicate_attr_policy: DuplicateAttrPolicy::Error,
+        ..Default::default()
+    };
+    let template = templates::greeting(list([attr("hidden"), attr("hidden")]), text("coyote"));
+    let expected = Errors::DuplicateAttribute("hidden".to_string(), "article".to_string());
+
+    let mut html = Html::from(&params);
+    html.prepare_compiled_steps(&templates::get_compiled_steps());
+    let results = html.render(&template);
+
+    assert_eq!(Err(expected), results);
+}
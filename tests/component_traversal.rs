@@ -0,0 +1,91 @@
+use coyotes::{
+    Component, Html, attr, attr_val, find_all, list, map_attrs, map_components, map_text, memo,
+    shared, text, tmpl, walk,
+};
+use std::sync::Arc;
+
+fn article() -> Component {
+    let nav = Arc::new(tmpl(
+        "<nav {}>{}</nav>",
+        [attr_val("data-track", "nav"), text("home")],
+    ));
+
+    tmpl(
+        "<article {}>{}</article>",
+        [
+            list([attr("hidden"), attr_val("data-track", "article")]),
+            list([
+                shared(&nav),
+                memo("greeting", tmpl("<p>{}</p>", [text("hai :3")])),
+                text("goodbye"),
+            ]),
+        ],
+    )
+}
+
+#[test]
+fn walk_visits_components_in_document_order() {
+    let mut texts = Vec::new();
+    walk(&article(), |cmpnt| {
+        if let Component::Text(text) = cmpnt {
+            texts.push(text.clone());
+        }
+    });
+
+    assert_eq!(Vec::from(["home", "hai :3", "goodbye"]), texts);
+}
+
+#[test]
+fn find_all_descends_into_injections() {
+    let template = article();
+    let found = find_all(&template, |cmpnt| match cmpnt {
+        Component::AttrVal(attr, _) => attr.starts_with("data-track"),
+        _ => false,
+    });
+
+    assert_eq!(
+        Vec::from([
+            &attr_val("data-track", "article"),
+            &attr_val("data-track", "nav")
+        ]),
+        found
+    );
+}
+
+#[test]
+fn map_text_translates_all_text() {
+    let template = map_text(article(), |text| text.to_uppercase());
+    let expected = "<article hidden data-track=\"article\"><nav data-track=\"nav\">HOME</nav><p>HAI :3</p>GOODBYE</article>";
+
+    let mut html = Html::new();
+    let results = html.render(&template);
+
+    assert_eq!(Ok(expected.to_string()), results);
+}
+
+#[test]
+fn map_attrs_strips_tracking_attributes() {
+    let template = map_attrs(article(), |cmpnt| match cmpnt {
+        Component::AttrVal(ref attr, _) if attr.starts_with("data-track") => Component::None,
+        _ => cmpnt,
+    });
+    let expected = "<article hidden><nav>home</nav><p>hai :3</p>goodbye</article>";
+
+    let mut html = Html::new();
+    let results = html.render(&template);
+
+    assert_eq!(Ok(expected.to_string()), results);
+}
+
+#[test]
+fn map_components_rewrites_descendants_first() {
+    let mut order = Vec::new();
+    let _ = map_components(list([text("a"), list([text("b")])]), |cmpnt| {
+        if let Component::Text(ref text) = cmpnt {
+            order.push(text.clone());
+        }
+        cmpnt
+    });
+
+    assert_eq!(Vec::from(["a", "b"]), order);
+}
@@ -0,0 +1,203 @@
+use coyotes::__private::{ChunkResults, MemoResults, TagInfo};
+use coyotes::{
+    DefaultBuilder, Document, DuplicateAttrPolicy, Html, HtmlRules, RulesetImpl,
+    TemplateBuilderImpl, TemplateSteps, text, tmpl,
+};
+
+// html rules that ban emphasis
+struct PlainRules {
+    rules: HtmlRules,
+}
+
+impl RulesetImpl for PlainRules {
+    fn get_document_memory_limit(&self) -> usize {
+        self.rules.get_document_memory_limit()
+    }
+
+    fn get_cache_memory_limit(&self) -> usize {
+        self.rules.get_cache_memory_limit()
+    }
+
+    fn get_alt_text_tag_from_close_sequence(&self, tag: &str) -> Option<&str> {
+        self.rules.get_alt_text_tag_from_close_sequence(tag)
+    }
+
+    fn get_close_sequence_from_alt_text_tag(&self, tag: &str) -> Option<&str> {
+        self.rules.get_close_sequence_from_alt_text_tag(tag)
+    }
+
+    fn get_close_sequence_from_contentless_tag(&self, tag: &str) -> Option<&str> {
+        self.rules.get_close_sequence_from_contentless_tag(tag)
+    }
+
+    fn get_contentless_tag_from_close_sequence(&self, tag: &str) -> Option<&str> {
+        self.rules.get_contentless_tag_from_close_sequence(tag)
+    }
+
+    fn get_duplicate_attr_policy(&self) -> DuplicateAttrPolicy {
+        self.rules.get_duplicate_attr_policy()
+    }
+
+    fn get_initial_embedded_content(&self) -> &str {
+        self.rules.get_initial_embedded_content()
+    }
+
+    fn keep_comments(&self) -> bool {
+        self.rules.keep_comments()
+    }
+
+    fn attr_is_mergeable(&self, attr: &str) -> bool {
+        self.rules.attr_is_mergeable(attr)
+    }
+
+    fn respect_indentation(&self) -> bool {
+        self.rules.respect_indentation()
+    }
+
+    fn tag_is_banned_el(&self, tag: &str) -> bool {
+        "em" == tag || self.rules.tag_is_banned_el(tag)
+    }
+
+    fn tag_is_comment_el(&self, tag: &str) -> bool {
+        self.rules.tag_is_comment_el(tag)
+    }
+
+    fn tag_is_inline_el(&self, tag: &str) -> bool {
+        self.rules.tag_is_inline_el(tag)
+    }
+
+    fn tag_is_embedded_content_el(&self, tag: &str) -> bool {
+        self.rules.tag_is_embedded_content_el(tag)
+    }
+
+    fn tag_is_prefix_of_contentless_el(&self, tag: &str) -> Option<&str> {
+        self.rules.tag_is_prefix_of_contentless_el(tag)
+    }
+
+    fn tag_is_preformatted_text_el(&self, tag: &str) -> bool {
+        self.rules.tag_is_preformatted_text_el(tag)
+    }
+
+    fn tag_is_rcdata_el(&self, tag: &str) -> bool {
+        self.rules.tag_is_rcdata_el(tag)
+    }
+
+    fn tag_is_template_el(&self, tag: &str) -> bool {
+        self.rules.tag_is_template_el(tag)
+    }
+
+    fn tag_is_void_el(&self, tag: &str) -> bool {
+        self.rules.tag_is_void_el(tag)
+    }
+}
+
+// a template builder that counts builds
+struct CountingBuilder {
+    builds: usize,
+    builder: DefaultBuilder,
+}
+
+impl TemplateBuilderImpl for CountingBuilder {
+    fn build(&mut self, rules: &dyn RulesetImpl, template_str: &str) -> TemplateSteps {
+        self.builds += 1;
+        self.builder.build(rules, template_str)
+    }
+
+    fn get_chunk(
+        &mut self,
+        rules: &dyn RulesetImpl,
+        template_str: &str,
+        index: usize,
+        tag_info_stack: &[TagInfo],
+    ) -> Option<ChunkResults> {
+        self.builder
+            .get_chunk(rules, template_str, index, tag_info_stack)
+    }
+
+    fn set_chunk(
+        &mut self,
+        rules: &dyn RulesetImpl,
+        template_str: &str,
+        index: usize,
+        tag_info_stack: &[TagInfo],
+        chunk: ChunkResults,
+    ) {
+        self.builder
+            .set_chunk(rules, template_str, index, tag_info_stack, chunk)
+    }
+
+    fn get_memo(
+        &mut self,
+        rules: &dyn RulesetImpl,
+        key: &str,
+        tag_info: &TagInfo,
+    ) -> Option<MemoResults> {
+        self.builder.get_memo(rules, key, tag_info)
+    }
+
+    fn set_memo(
+        &mut self,
+        rules: &dyn RulesetImpl,
+        key: &str,
+        tag_info: &TagInfo,
+        memo: MemoResults,
+    ) {
+        self.builder.set_memo(rules, key, tag_info, memo)
+    }
+}
+
+#[test]
+fn document_renders_with_custom_rules() {
+    let template = tmpl("<p>hai <em>{}</em>:3</p>", [text("coyote")]);
+    let expected = "<p>hai:3</p>";
+
+    let mut document = Document::with_rules(PlainRules {
+        rules: HtmlRules::new(),
+    });
+    let results = document.render(&template);
+
+    assert_eq!(Ok(expected.to_string()), results);
+}
+
+#[test]
+fn document_renders_with_custom_builder() {
+    let template = tmpl("<p>hai {}:3</p>", [text("coyote")]);
+    let expected = "<p>hai coyote:3</p>";
+
+    let builder = CountingBuilder {
+        builds: 0,
+        builder: DefaultBuilder::new(),
+    };
+
+    let mut document = Document::with_builder(HtmlRules::new(), builder);
+    let _ = document.render(&template);
+    let results = document.render(&template);
+
+    assert_eq!(Ok(expected.to_string()), results);
+    assert_eq!(2, document.get_builder().builds);
+}
+
+#[test]
+fn html_is_a_document() {
+    let template = tmpl("<p>hai {}:3</p>", [text("coyote")]);
+
+    let mut html: Document<HtmlRules> = Html::new();
+    let results = html.render(&template);
+
+    let mut document = Document::with_rules(HtmlRules::new());
+    let expected = document.render(&template);
+
+    assert_eq!(expected, results);
+}
+
+#[test]
+fn default_builder_clears_caches() {
+    let template = tmpl("<p>hai {}:3</p>", [text("coyote")]);
+
+    let mut html = Html::new();
+    let _ = html.render(&template);
+    assert!(0 < html.get_builder().get_memory_footprint());
+
+    html.get_builder_mut().clear();
+    assert_eq!(0, html.get_builder().get_memory_footprint());
+}
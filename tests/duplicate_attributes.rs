@@ -0,0 +1,180 @@
+use coyotes::{
+    Component, DocumentParams, DuplicateAttrPolicy, Errors, Html, Xml, attr, attr_val, classes,
+    list, tmpl,
+};
+
+fn text_input() -> Component {
+    tmpl("<input type=text {}>", [attr_val("type", "email")])
+}
+
+fn mixed_case_input() -> Component {
+    tmpl("<input TYPE=text {}>", [attr_val("type", "email")])
+}
+
+fn mixed_case_classes() -> Component {
+    tmpl("<p CLASS=\"howl\" {}></p>", [classes(["awoo", "howl"])])
+}
+
+fn html_with_policy(duplicate_attr_policy: DuplicateAttrPolicy) -> Html {
+    let params = DocumentParams {
+        duplicate_attr_policy,
+        ..Default::default()
+    };
+
+    Html::from(&params)
+}
+
+fn link_with_rels() -> Component {
+    tmpl(
+        "<a rel=\"noopener\" href=\"/uwu\" {}></a>",
+        [list([
+            attr_val("rel", "noreferrer noopener"),
+            attr("hidden"),
+        ])],
+    )
+}
+
+#[test]
+fn duplicate_attributes_first_wins() {
+    let expected = "<input type=text>";
+
+    let params = DocumentParams {
+        duplicate_attr_policy: DuplicateAttrPolicy::FirstWins,
+        ..Default::default()
+    };
+
+    let mut html = Html::from(&params);
+    let results = html.render(&text_input());
+
+    assert_eq!(Ok(expected.to_string()), results);
+}
+
+#[test]
+fn duplicate_attributes_last_wins() {
+    let expected = "<input type=\"email\">";
+
+    let params = DocumentParams {
+        duplicate_attr_policy: DuplicateAttrPolicy::LastWins,
+        ..Default::default()
+    };
+
+    let mut html = Html::from(&params);
+    let results = html.render(&text_input());
+
+    assert_eq!(Ok(expected.to_string()), results);
+}
+
+#[test]
+fn duplicate_attributes_error() {
+    let params = DocumentParams {
+        duplicate_attr_policy: DuplicateAttrPolicy::Error,
+        ..Default::default()
+    };
+
+    let mut html = Html::from(&params);
+    let results = html.render(&text_input());
+
+    assert_eq!(
+        Err(Errors::DuplicateAttribute(
+            "type".to_string(),
+            "input".to_string()
+        )),
+        results
+    );
+}
+
+#[test]
+fn duplicate_attributes_merge_token_lists() {
+    let expected = "<a rel=\"noopener noreferrer\" href=\"/uwu\" hidden></a>";
+
+    let params = DocumentParams {
+        duplicate_attr_policy: DuplicateAttrPolicy::Merge,
+        ..Default::default()
+    };
+
+    let mut html = Html::from(&params);
+    let results = html.render(&link_with_rels());
+
+    assert_eq!(Ok(expected.to_string()), results);
+}
+
+#[test]
+fn duplicate_attributes_merge_keeps_first_of_other_attributes() {
+    let expected = "<input type=text>";
+
+    let mut html = Html::new();
+    let results = html.render(&text_input());
+
+    assert_eq!(Ok(expected.to_string()), results);
+}
+
+#[test]
+fn duplicate_template_attributes_are_resolved() {
+    let template = tmpl("<p id=a hidden id=\"b\"/>", []);
+    let expected = "<p hidden id=\"b\"></p>";
+
+    let params = DocumentParams {
+        duplicate_attr_policy: DuplicateAttrPolicy::LastWins,
+        ..Default::default()
+    };
+
+    let mut html = Html::from(&params);
+    let results = html.render(&template);
+
+    assert_eq!(Ok(expected.to_string()), results);
+}
+
+#[test]
+fn mixed_case_duplicate_attributes_first_wins() {
+    let expected = "<input TYPE=text>";
+
+    let mut html = html_with_policy(DuplicateAttrPolicy::FirstWins);
+    let results = html.render(&mixed_case_input());
+
+    assert_eq!(Ok(expected.to_string()), results);
+}
+
+#[test]
+fn mixed_case_duplicate_attributes_last_wins() {
+    let expected = "<input type=\"email\">";
+
+    let mut html = html_with_policy(DuplicateAttrPolicy::LastWins);
+    let results = html.render(&mixed_case_input());
+
+    assert_eq!(Ok(expected.to_string()), results);
+}
+
+#[test]
+fn mixed_case_duplicate_attributes_error() {
+    let mut html = html_with_policy(DuplicateAttrPolicy::Error);
+    let results = html.render(&mixed_case_input());
+
+    assert_eq!(
+        Err(Errors::DuplicateAttribute(
+            "TYPE".to_string(),
+            "input".to_string()
+        )),
+        results
+    );
+}
+
+#[test]
+fn mixed_case_duplicate_attributes_merge() {
+    let expected = "<p CLASS=\"howl awoo\"></p>";
+
+    let mut html = html_with_policy(DuplicateAttrPolicy::Merge);
+    let results = html.render(&mixed_case_classes());
+
+    assert_eq!(Ok(expected.to_string()), results);
+}
+
+#[test]
+fn mixed_case_attributes_are_distinct_in_xml() {
+    let template = tmpl("<input TYPE=\"text\" {}/>", [attr_val("type", "email")]);
+    let expected = "<input TYPE=\"text\" type=\"email\"/>";
+
+    let mut xml = Xml::new();
+    let results = xml.render(&template);
+
+    assert_eq!(Ok(expected.to_string()), results);
+}
@@ -0,0 +1,129 @@
+use coyotes::{
+    Component, Errors, Html, Xml, area, base, br, col, el, embed, hr, img, input, link, list, meta,
+    source, text, tmpl, track, wbr,
+};
+
+fn submit_button(is_primary: bool) -> Component {
+    el("button")
+        .attr("type", "submit")
+        .class("button")
+        .class_if(is_primary, "button--primary")
+        .class_if(!is_primary, "button--secondary")
+        .child(text("save"))
+        .build()
+}
+
+#[test]
+fn element_builder_renders_like_template() {
+    let expected = "<button type=\"submit\" class=\"button button--primary\">save</button>";
+
+    let mut html = Html::new();
+    let results = html.render(&submit_button(true));
+
+    assert_eq!(Ok(expected.to_string()), results);
+}
+
+#[test]
+fn element_builders_nest() {
+    let template = el("form")
+        .attr("action", "/uwu")
+        .attr_bool("novalidate", true)
+        .child(
+            input()
+                .attr("name", "name")
+                .attr_opt("placeholder", Some("a good dog"))
+                .attr_bool("required", false),
+        )
+        .child(
+            img()
+                .attr_url("src", "/coyote howl.png")
+                .attr_num("width", 64),
+        )
+        .child(submit_button(false))
+        .build();
+    let expected = "<form action=\"/uwu\" novalidate><input name=\"name\" placeholder=\"a good dog\"><img src=\"/coyote%20howl.png\" width=\"64\"><button type=\"submit\" class=\"button button--secondary\">save</button></form>";
+
+    let mut html = Html::new();
+    let results = html.render(&template);
+
+    assert_eq!(Ok(expected.to_string()), results);
+}
+
+#[test]
+fn element_builders_inject_into_templates() {
+    let template = tmpl(
+        "
+        <main>
+            {}
+        </main>
+        ",
+        [el("p")
+            .style("color", "red")
+            .children([text("hai"), text(" :3")])
+            .into()],
+    );
+    let expected = "<main>\n\t<p style=\"color: red\">hai :3</p>\n</main>";
+
+    let mut html = Html::new();
+    let results = html.render(&template);
+
+    assert_eq!(Ok(expected.to_string()), results);
+}
+
+#[test]
+fn void_tags_render_without_descendants() {
+    let template = el("br").build();
+    let expected = "<br>";
+
+    let mut html = Html::new();
+    let results = html.render(&template);
+
+    assert_eq!(Ok(expected.to_string()), results);
+}
+
+#[test]
+fn void_tags_with_descendants_are_rejected() {
+    let template = el("br").child(text("hai :3")).build();
+    let expected = Errors::UnbalancedTemplate("<br {}>{}</br>".to_string());
+
+    let mut html = Html::new();
+    let results = html.render(&template);
+
+    assert_eq!(Err(expected), results);
+}
+
+#[test]
+fn void_tags_with_descendants_render_in_xml() {
+    let template = el("br").child(text("hai :3")).build();
+    let expected = "<br>hai :3</br>";
+
+    let mut xml = Xml::new();
+    let results = xml.render(&template);
+
+    assert_eq!(Ok(expected.to_string()), results);
+}
+
+#[test]
+fn void_functions_follow_void_elements() {
+    let template = list([
+        area().build(),
+        base().build(),
+        br().build(),
+        col().build(),
+        embed().build(),
+        hr().build(),
+        img().build(),
+        input().build(),
+        link().build(),
+        meta().build(),
+        source().build(),
+        track().build(),
+        wbr().build(),
+    ]);
+    let expected = "<area><base><br><col><embed><hr><img><input><link><meta><source><track><wbr>";
+
+    let mut html = Html::new();
+    let results = html.render(&template);
+
+    assert_eq!(Ok(expected.to_string()), results);
+}
@@ -0,0 +1,151 @@
+use coyotes::{DocumentParams, ElementSet, ElementSets, Html, HtmlOnly, text, tmpl};
+
+#[test]
+fn element_sets_include_inline_elements() {
+    let template = tmpl(
+        "
+        <p>
+            hai <coyote-icon>:3</coyote-icon>
+        </p>
+        ",
+        [],
+    );
+    let expected = "<p>\n\thai <coyote-icon>:3</coyote-icon>\n</p>";
+
+    let mut elements = ElementSets::new();
+    elements.inline = ElementSet::new().include("coyote-icon");
+
+    let params = DocumentParams {
+        elements,
+        ..Default::default()
+    };
+
+    let mut html = Html::from(&params);
+    let results = html.render(&template);
+
+    assert_eq!(Ok(expected.to_string()), results);
+}
+
+#[test]
+fn element_sets_include_preformatted_elements() {
+    let template = tmpl(
+        "<listing>
+  hai   :3
+</listing>",
+        [],
+    );
+    let expected = "<listing>\n  hai   :3\n</listing>";
+
+    let mut elements = ElementSets::new();
+    elements.preformatted = ElementSet::new().include("listing");
+
+    let params = DocumentParams {
+        elements,
+        ..Default::default()
+    };
+
+    let mut html = Html::from(&params);
+    let results = html.render(&template);
+
+    assert_eq!(Ok(expected.to_string()), results);
+}
+
+#[test]
+fn element_sets_include_void_elements() {
+    let template = tmpl("<p>hai<coyote-break>:3</p>", []);
+    let expected = "<p>hai<coyote-break>:3</p>";
+
+    let mut elements = ElementSets::new();
+    elements.void = ElementSet::new().include("coyote-break");
+
+    let params = DocumentParams {
+        elements,
+        ..Default::default()
+    };
+
+    let mut html = Html::from(&params);
+    let results = html.render(&template);
+
+    assert_eq!(Ok(expected.to_string()), results);
+}
+
+#[test]
+fn element_sets_exclude_rcdata_elements() {
+    let template = tmpl("<title>  hai   <b>:3</b>  </title>", []);
+    let expected = "<title> hai <b>:3</b> </title>";
+
+    let mut elements = ElementSets::new();
+    elements.rcdata = ElementSet::new().exclude("title");
+
+    let params = DocumentParams {
+        elements,
+        ..Default::default()
+    };
+
+    let mut html = Html::from(&params);
+    let results = html.render(&template);
+
+    assert_eq!(Ok(expected.to_string()), results);
+}
+
+#[test]
+fn element_sets_include_raw_text_elements() {
+    let template = tmpl("<coyote-code><p>{}</p></coyote-code>", []);
+    let expected = "<coyote-code><p>{}</p></coyote-code>";
+
+    let mut elements = ElementSets::new();
+    elements.raw_text = ElementSet::new().include("coyote-code");
+
+    let params = DocumentParams {
+        elements,
+        ..Default::default()
+    };
+
+    let mut html = Html::from(&params);
+    let results = html.render(&template);
+
+    assert_eq!(Ok(expected.to_string()), results);
+}
+
+#[test]
+fn element_sets_include_rcdata_elements() {
+    let template = tmpl("<coyote-note>  <b>{}</b>  </coyote-note>", [text("hai")]);
+    let expected = "<coyote-note>  <b>hai</b>  </coyote-note>";
+
+    let mut elements = ElementSets::new();
+    elements.rcdata = ElementSet::new().include("coyote-note");
+
+    let params = DocumentParams {
+        elements,
+        ..Default::default()
+    };
+
+    let mut html = Html::from(&params);
+    let results = html.render(&template);
+
+    assert_eq!(Ok(expected.to_string()), results);
+}
+
+#[test]
+fn element_sets_exclude_void_elements_with_html_only() {
+    let template = tmpl("<p>hai<br>:3</br></p>", []);
+    let expected = "<p>hai<br>:3</br></p>";
+
+    let mut elements = ElementSets::new();
+    elements.void = ElementSet::new().exclude("br");
+
+    let params = DocumentParams {
+        elements,
+        ..Default::default()
+    };
+
+    let mut html = HtmlOnly::from(&params);
+    let results = html.render(&template);
+
+    assert_eq!(Ok(expected.to_string()), results);
+
+    let mut html = HtmlOnly::new();
+    let results = html.render(&template);
+
+    assert_ne!(Ok(expected.to_string()), results);
+}
@@ -0,0 +1,161 @@
+use coyotes::{
+    Component, DocumentParams, DuplicateAttrPolicy, ErrorHook, Errors, Html, RenderContext, attr,
+    attr_val, boundary, list, text, tmpl,
+};
+use std::sync::{Arc, Mutex};
+
+fn widget() -> Component {
+    tmpl(
+        "
+        <section>
+            <p>{}</p>
+        ",
+        [text("unbalanced")],
+    )
+}
+
+fn page(content: Component) -> Component {
+    tmpl(
+        "
+        <main>
+            {}
+        </main>
+        ",
+        [content],
+    )
+}
+
+#[test]
+fn boundary_renders_component_without_errors() {
+    let template = page(boundary(text("hai :3"), text("oh no")));
+    let expected = "<main>\n\thai :3\n</main>";
+
+    let mut html = Html::new();
+    let results = html.render(&template);
+
+    assert_eq!(Ok(expected.to_string()), results);
+}
+
+#[test]
+fn boundary_discards_partial_results() {
+    let template = page(list([
+        text("before"),
+        boundary(widget(), tmpl("<p>widget unavailable</p>", [])),
+        tmpl("<p>after</p>", []),
+    ]));
+    let expected = "<main>\n\tbefore<p>widget unavailable</p><p>after</p>\n</main>";
+
+    let mut html = Html::new();
+    let results = html.render(&template);
+
+    assert_eq!(Ok(expected.to_string()), results);
+}
+
+#[test]
+fn errors_outside_of_boundaries_abort_documents() {
+    let mut html = Html::new();
+    let results = html.render(&page(widget()));
+
+    assert!(matches!(results, Err(Errors::UnbalancedTemplate(_))));
+}
+
+#[test]
+fn boundary_catches_memory_limit_errors() {
+    let params = DocumentParams {
+        cache_memory_limit: 1024,
+        document_memory_limit: 64,
+        ..Default::default()
+    };
+    let template = tmpl(
+        "<p>{}</p>",
+        [boundary(
+            list([text(&"a".repeat(64)), text("too long")]),
+            text("truncated"),
+        )],
+    );
+    let expected = "<p>truncated</p>";
+
+    let mut html = Html::from(&params);
+    let results = html.render(&template);
+
+    assert_eq!(Ok(expected.to_string()), results);
+}
+
+#[test]
+fn boundary_catches_attribute_errors() {
+    let template = tmpl(
+        "<input {}>",
+        [list([
+            attr_val("type", "text"),
+            boundary(
+                list([attr("required"), attr("on\"click")]),
+                attr_val("data-invalid", "true"),
+            ),
+        ])],
+    );
+    let expected = "<input type=\"text\" data-invalid=\"true\">";
+
+    let mut html = Html::new();
+    let results = html.render(&template);
+
+    assert_eq!(Ok(expected.to_string()), results);
+}
+
+#[test]
+fn boundary_catches_duplicate_attributes() {
+    let params = DocumentParams {
+        duplicate_attr_policy: DuplicateAttrPolicy::Error,
+        ..Default::default()
+    };
+    let template = tmpl(
+        "<p id=a {}></p>",
+        [boundary(attr_val("id", "b"), attr("ok"))],
+    );
+    let expected = "<p id=a ok></p>";
+
+    let mut html = Html::from(&params);
+    let results = html.render(&template);
+
+    assert_eq!(Ok(expected.to_string()), results);
+}
+
+#[test]
+fn boundary_misses_duplicates_of_later_attributes() {
+    let params = DocumentParams {
+        duplicate_attr_policy: DuplicateAttrPolicy::Error,
+        ..Default::default()
+    };
+    let template = tmpl(
+        "<p {} id=a></p>",
+        [boundary(attr_val("id", "b"), attr("ok"))],
+    );
+    let expected = Errors::DuplicateAttribute("id".to_string(), "p".to_string());
+
+    let mut html = Html::from(&params);
+    let results = html.render(&template);
+
+    assert_eq!(Err(expected), results);
+}
+
+#[test]
+fn boundary_reports_swallowed_errors() {
+    let reported: Arc<Mutex<Vec<Errors>>> = Arc::new(Mutex::new(Vec::new()));
+    let hook_reported = reported.clone();
+    let context = RenderContext::new().with(ErrorHook::new(move |error| {
+        if let Ok(mut errors) = hook_reported.lock() {
+            errors.push(error.clone());
+        }
+    }));
+
+    let template = page(boundary(widget(), Component::None));
+    let expected = "<main>\n</main>";
+
+    let mut html = Html::new();
+    let results = html.render_with_context(&template, &context);
+
+    assert_eq!(Ok(expected.to_string()), results);
+
+    let errors = reported.lock().unwrap();
+    assert_eq!(1, errors.len());
+    assert!(matches!(errors[0], Errors::UnbalancedTemplate(_)));
+}
@@ -0,0 +1,929 @@
+// generated by coyotes::compile_templates, do not edit
+
+pub const CARD_TEMPLATE: &str = "<section class=\"card\">\n\t<h2>{}</h2>\n\t<p>a few words   with spaces\n\t\tand lines</p>\n\t<pre>\n  keep\n\tthis\n</pre>\n\t{}\n\t<input type=\"text\"\n\t\tname=\"howl\">\n</section>\n";
+
+pub fn card(descendants_0: coyotes::Component, descendants_1: coyotes::Component) -> coyotes::Component {
+    coyotes::tmpl(CARD_TEMPLATE, [descendants_0, descendants_1])
+}
+
+pub const GREETING_TEMPLATE: &str = "<article {}>\n\t<p>hai {} :3</p>\n</article>";
+
+pub fn greeting(attrs_0: coyotes::Component, descendants_1: coyotes::Component) -> coyotes::Component {
+    coyotes::tmpl(GREETING_TEMPLATE, [attrs_0, descendants_1])
+}
+
+pub const INLINE_TEMPLATE: &str = "<span>{}</span> and <b>bold</b> text {} more <img src=\"coyote.png\"/>";
+
+pub fn inline(descendants_0: coyotes::Component, descendants_1: coyotes::Component) -> coyotes::Component {
+    coyotes::tmpl(INLINE_TEMPLATE, [descendants_0, descendants_1])
+}
+
+pub const PAGE_TEMPLATE: &str = "<!DOCTYPE html>\n<html>\n\t<head>\n\t\t<title>{}</title>\n\t\t<meta charset=\"utf-8\">\n\t</head>\n\t<body {}>\n\t\t<main>\n\t\t\t<h1>hai coyotes</h1>\n\t\t\t{}\n\t\t\t<ul>\n\t\t\t\t<li>one</li>\n\t\t\t\t<li>two</li>\n\t\t\t</ul>\n\t\t</main>\n\t\t<footer>:3</footer>\n\t</body>\n</html>\n";
+
+pub fn page(descendants_0: coyotes::Component, attrs_1: coyotes::Component, descendants_2: coyotes::Component) -> coyotes::Component {
+    coyotes::tmpl(PAGE_TEMPLATE, [descendants_0, attrs_1, descendants_2])
+}
+
+pub const RAW_TEXT_TEMPLATE: &str = "<script>\n\tconst howl = \"<p>\";\n</script>\n<style>p { color: red; }</style>\n<textarea>\n\t{}</textarea>\n<!-- a comment -->\n";
+
+pub fn raw_text(descendants_0: coyotes::Component) -> coyotes::Component {
+    coyotes::tmpl(RAW_TEXT_TEMPLATE, [descendants_0])
+}
+
+pub fn get_template_steps() -> Vec<(&'static str, coyotes::TemplateSteps)> {
+    Vec::from([
+        (
+            CARD_TEMPLATE,
+            coyotes::TemplateSteps {
+                steps: Vec::from([
+                    Vec::from([
+                        coyotes::__private::Step { kind: coyotes::__private::StepKind::Initial, origin: 0, target: 0 },
+                        coyotes::__private::Step { kind: coyotes::__private::StepKind::Element, origin: 0, target: 1 },
+                        coyotes::__private::Step { kind: coyotes::__private::StepKind::Tag, origin: 1, target: 8 },
+                        coyotes::__private::Step { kind: coyotes::__private::StepKind::ElementSpace, origin: 8, target: 9 },
+                        coyotes::__private::Step { kind: coyotes::__private::StepKind::Attr, origin: 9, target: 14 },
+                        coyotes::__private::Step { kind: coyotes::__private::StepKind::AttrSetter, origin: 14, target: 15 },
+                        coyotes::__private::Step { kind: coyotes::__private::StepKind::AttrDoubleQuote, origin: 15, target: 16 },
+                        coyotes::__private::Step { kind: coyotes::__private::StepKind::AttrValueDoubleQuoted, origin: 16, target: 20 },
+                        coyotes::__private::Step { kind: coyotes::__private::StepKind::AttrDoubleQuoteClosed, origin: 20, target: 21 },
+                        coyotes::__private::Step { kind: coyotes::__private::StepKind::ElementClosed, origin: 21, target: 22 },
+                        coyotes::__private::Step { kind: coyotes::__private::StepKind::TextLineSpace, origin: 22, target: 23 },
+                        coyotes::__private::Step { kind: coyotes::__private::StepKind::TextSpace, origin: 23, target: 24 },
+                        coyotes::__private::Step { kind: coyotes::__private::StepKind::Element, origin: 24, target: 25 },
+                        coyotes::__private::Step { kind: coyotes::__private::StepKind::Tag, origin: 25, target: 27 },
+                        coyotes::__private::Step { kind: coyotes::__private::StepKind::ElementClosed, origin: 27, target: 28 },
+                    ]),
+                    Vec::from([
+                        coyotes::__private::Step { kind: coyotes::__private::StepKind::InjectionConfirmed, origin: 29, target: 30 },
+                        coyotes::__private::Step { kind: coyotes::__private::StepKind::Element, origin: 30, target: 31 },
+                        coyotes::__private::Step { kind: coyotes::__private::StepKind::TailElementSolidus, origin: 31, target: 32 },
+                        coyotes::__private::Step { kind: coyotes::__private::StepKind::TailTag, origin: 32, target: 34 },
+                        coyotes::__private::Step { kind: coyotes::__private::StepKind::TailElementClosed, origin: 34, target: 35 },
+                        coyotes::__private::Step { kind: coyotes::__private::StepKind::TextLineSpace, origin: 35, target: 36 },
+                        coyotes::__private::Step { kind: coyotes::__private::StepKind::TextSpace, origin: 36, target: 37 },
+                        coyotes::__private::Step { kind: coyotes::__private::StepKind::Element, origin: 37, target: 38 },
+                        coyotes::__private::Step { kind: coyotes::__private::StepKind::Tag, origin: 38, target: 39 },
+                        coyotes::__private::Step { kind: coyotes::__private::StepKind::ElementClosed, origin: 39, target: 40 },
+                        coyotes::__private::Step { kind: coyotes::__private::StepKind::Text, origin: 40, target: 41 },
+                        coyotes::__private::Step { kind: coyotes::__private::StepKind::TextSpace, origin: 41, target: 42 },
+                        coyotes::__private::Step { kind: coyotes::__private::StepKind::Text, origin: 42, target: 45 },
+                        coyotes::__private::Step { kind: coyotes::__private::StepKind::TextSpace, origin: 45, target: 46 },
+                        coyotes::__private::Step { kind: coyotes::__private::StepKind::Text, origin: 46, target: 51 },
+                        coyotes::__private::Step { kind: coyotes::__private::StepKind::TextSpace, origin: 51, target: 54 },
+                        coyotes::__private::Step { kind: coyotes::__private::StepKind::Text, origin: 54, target: 58 },
+                        coyotes::__private::Step { kind: coyotes::__private::StepKind::TextSpace, origin: 58, target: 59 },
+                        coyotes::__private::Step { kind: coyotes::__private::StepKind::Text, origin: 59, target: 65 },
+                        coyotes::__private::Step { kind: coyotes::__private::StepKind::TextLineSpace, origin: 65, target: 66 },
+                        coyotes::__private::Step { kind: coyotes::__private::StepKind::TextSpace, origin: 66, target: 68 },
+                        coyotes::__private::Step { kind: coyotes::__private::StepKind::Text, origin: 68, target: 71 },
+                        coyotes::__private::Step { kind: coyotes::__private::StepKind::TextSpace, origin: 71, target: 72 },
+                        coyotes::__private::Step { kind: coyotes::__private::StepKind::Text, origin: 72, target: 77 },
+                        coyotes::__private::Step { kind: coyotes::__private::StepKind::Element, origin: 77, target: 78 },
+                        coyotes::__private::Step { kind: coyotes::__private::StepKind::TailElementSolidus, origin: 78, target: 79 },
+                        coyotes::__private::Step { kind: coyotes::__private::StepKind::TailTag, origin: 79, target: 80 },
+                        coyotes::__private::Step { kind: coyotes::__private::StepKind::TailElementClosed, origin: 80, target: 81 },
+                        coyotes::__private::Step { kind: coyotes::__private::StepKind::TextLineSpace, origin: 81, target: 82 },
+                        coyotes::__private::Step { kind: coyotes::__private::StepKind::TextSpace, origin: 82, target: 83 },
+                        coyotes::__private::Step { kind: coyotes::__private::StepKind::Element, origin: 83, target: 84 },
+                        coyotes::__private::Step { kind: coyotes::__private::StepKind::Tag, origin: 84, target: 87 },
+                        coyotes::__private::Step { kind: coyotes::__private::StepKind::ElementClosed, origin: 87, target: 88 },
+                        coyotes::__private::Step { kind: coyotes::__private::StepKind::TextLineSpace, origin: 88, target: 89 },
+                        coyotes::__private::Step { kind: coyotes::__private::StepKind::TextSpace, origin: 89, target: 91 },
+                        coyotes::__private::Step { kind: coyotes::__private::StepKind::Text, origin: 91, target: 95 },
+                        coyotes::__private::Step { kind: coyotes::__private::StepKind::TextLineSpace, origin: 95, target: 96 },
+                        coyotes::__private::Step { kind: coyotes::__private::StepKind::TextSpace, origin: 96, target: 97 },
+                        coyotes::__private::Step { kind: coyotes::__private::StepKind::Text, origin: 97, target: 101 },
+                        coyotes::__private::Step { kind: coyotes::__private::StepKind::TextLineSpace, origin: 101, target: 102 },
+                        coyotes::__private::Step { kind: coyotes::__private::StepKind::Element, origin: 102, target: 103 },
+                        coyotes::__private::Step { kind: coyotes::__private::StepKind::TailElementSolidus, origin: 103, target: 104 },
+                        coyotes::__private::Step { kind: coyotes::__private::StepKind::TailTag, origin: 104, target: 107 },
+                        coyotes::__private::Step { kind: coyotes::__private::StepKind::TailElementClosed, origin: 107, target: 108 },
+                        coyotes::__private::Step { kind: coyotes::__private::StepKind::TextLineSpace, origin: 108, target: 109 },
+                        coyotes::__private::Step { kind: coyotes::__private::StepKind::TextSpace, origin: 109, target: 110 },
+                    ]),
+                    Vec::from([
+                        coyotes::__private::Step { kind: coyotes::__private::StepKind::InjectionConfirmed, origin: 111, target: 112 },
+                        coyotes::__private::Step { kind: coyotes::__private::StepKind::TextLineSpace, origin: 112, target: 113 },
+                        coyotes::__private::Step { kind: coyotes::__private::StepKind::TextSpace, origin: 113, target: 114 },
+                        coyotes::__private::Step { kind: coyotes::__private::StepKind::Element, origin: 114, target: 115 },
+                        coyotes::__private::Step { kind: coyotes::__private::StepKind::Tag, origin: 115, target: 120 },
+                        coyotes::__private::Step { kind: coyotes::__private::StepKind::ElementSpace, origin: 120, target: 121 },
+                        coyotes::__private::Step { kind: coyotes::__private::StepKind::Attr, origin: 121, target: 125 },
+                        coyotes::__private::Step { kind: coyotes::__private::StepKind::AttrSetter, origin: 125, target: 126 },
+                        coyotes::__private::Step { kind: coyotes::__private::StepKind::AttrDoubleQuote, origin: 126, target: 127 },
+                        coyotes::__private::Step { kind: coyotes::__private::StepKind::AttrValueDoubleQuoted, origin: 127, target: 131 },
+                        coyotes::__private::Step { kind: coyotes::__private::StepKind::AttrDoubleQuoteClosed, origin: 131, target: 132 },
+                        coyotes::__private::Step { kind: coyotes::__private::StepKind::ElementLineSpace, origin: 132, target: 133 },
+                        coyotes::__private::Step { kind: coyotes::__private::StepKind::ElementSpace, origin: 133, target: 135 },
+                        coyotes::__private::Step { kind: coyotes::__private::StepKind::Attr, origin: 135, target: 139 },
+                        coyotes::__private::Step { kind: coyotes::__private::StepKind::AttrSetter, origin: 139, target: 140 },
+                        coyotes::__private::Step { kind: coyotes::__private::StepKind::AttrDoubleQuote, origin: 140, target: 141 },
+                        coyotes::__private::Step { kind: coyotes::__private::StepKind::AttrValueDoubleQuoted, origin: 141, target: 145 },
+                        coyotes::__private::Step { kind: coyotes::__private::StepKind::AttrDoubleQuoteClosed, origin: 145, target: 146 },
+                        coyotes::__private::Step { kind: coyotes::__private::StepKind::ElementClosed, origin: 146, target: 147 },
+                        coyotes::__private::Step { kind: coyotes::__private::StepKind::TextLineSpace, origin: 147, target: 148 },
+                        coyotes::__private::Step { kind: coyotes::__private::StepKind::Element, origin: 148, target: 149 },
+                        coyotes::__private::Step { kind: coyotes::__private::StepKind::TailElementSolidus, origin: 149, target: 150 },
+                        coyotes::__private::Step { kind: coyotes::__private::StepKind::TailTag, origin: 150, target: 157 },
+                        coyotes::__private::Step { kind: coyotes::__private::StepKind::TailElementClosed, origin: 157, target: 158 },
+                        coyotes::__private::Step { kind: coyotes::__private::StepKind::TextLineSpace, origin: 158, target: 159 },
+                    ]),
+                ]),
+                injs: Vec::from([
+                    coyotes::__private::Step { kind: coyotes::__private::StepKind::DescendantInjection, origin: 28, target: 29 },
+                    coyotes::__private::Step { kind: coyotes::__private::StepKind::DescendantInjection, origin: 110, target: 111 },
+                ]),
+            },
+        ),
+        (
+            GREETING_TEMPLATE,
+            coyotes::TemplateSteps {
+                steps: Vec::from([
+                    Vec::from([
+                        coyotes::__private::Step { kind: coyotes::__private::StepKind::Initial, origin: 0, target: 0 },
+                        coyotes::__private::Step { kind: coyotes::__private::StepKind::Element, origin: 0, target: 1 },
+                        coyotes::__private::Step { kind: coyotes::__private::StepKind::Tag, origin: 1, target: 8 },
+                        coyotes::__private::Step { kind: coyotes::__private::StepKind::ElementSpace, origin: 8, target: 9 },
+                    ]),
+                    Vec::from([
+                        coyotes::__private::Step { kind: coyotes::__private::StepKind::InjectionConfirmed, origin: 10, target: 11 },
+                        coyotes::__private::Step { kind: coyotes::__private::StepKind::ElementClosed, origin: 11, target: 12 },
+                        coyotes::__private::Step { kind: coyotes::__private::StepKind::TextLineSpace, origin: 12, target: 13 },
+                        coyotes::__private::Step { kind: coyotes::__private::StepKind::TextSpace, origin: 13, target: 14 },
+                        coyotes::__private::Step { kind: coyotes::__private::StepKind::Element, origin: 14, target: 15 },
+                        coyotes::__private::Step { kind: coyotes::__private::StepKind::Tag, origin: 15, target: 16 },
+                        coyotes::__private::Step { kind: coyotes::__private::StepKind::ElementClosed, origin: 16, target: 17 },
+                        coyotes::__private::Step { kind: coyotes::__private::StepKind::Text, origin: 17, target: 20 },
+                        coyotes::__private::Step { kind: coyotes::__private::StepKind::TextSpace, origin: 20, target: 21 },
+                    ]),
+                    Vec::from([
+                        coyotes::__private::Step { kind: coyotes::__private::StepKind::InjectionConfirmed, origin: 22, target: 23 },
+                        coyotes::__private::Step { kind: coyotes::__private::StepKind::TextSpace, origin: 23, target: 24 },
+                        coyotes::__private::Step { kind: coyotes::__private::StepKind::Text, origin: 24, target: 26 },
+                        coyotes::__private::Step { kind: coyotes::__private::StepKind::Element, origin: 26, target: 27 },
+                        coyotes::__private::Step { kind: coyotes::__private::StepKind::TailElementSolidus, origin: 27, target: 28 },
+                        coyotes::__private::Step { kind: coyotes::__private::StepKind::TailTag, origin: 28, target: 29 },
+                        coyotes::__private::Step { kind: coyotes::__private::StepKind::TailElementClosed, origin: 29, target: 30 },
+                        coyotes::__private::Step { kind: coyotes::__private::StepKind::TextLineSpace, origin: 30, target: 31 },
+                        coyotes::__private::Step { kind: coyotes::__private::StepKind::Element, origin: 31, target: 32 },
+                        coyotes::__private::Step { kind: coyotes::__private::StepKind::TailElementSolidus, origin: 32, target: 33 },
+                        coyotes::__private::Step { kind: coyotes::__private::StepKind::TailTag, origin: 33, target: 40 },
+                        coyotes::__private::Step { kind: coyotes::__private::StepKind::TailElementClosed, origin: 40, target: 41 },
+                    ]),
+                ]),
+                injs: Vec::from([
+                    coyotes::__private::Step { kind: coyotes::__private::StepKind::AttrMapInjection, origin: 9, target: 10 },
+                    coyotes::__private::Step { kind: coyotes::__private::StepKind::DescendantInjection, origin: 21, target: 22 },
+                ]),
+            },
+        ),
+        (
+            INLINE_TEMPLATE,
+            coyotes::TemplateSteps {
+                steps: Vec::from([
+                    Vec::from([
+                        coyotes::__private::Step { kind: coyotes::__private::StepKind::Initial, origin: 0, target: 0 },
+                        coyotes::__private::Step { kind: coyotes::__private::StepKind::Element, origin: 0, target: 1 },
+                        coyotes::__private::Step { kind: coyotes::__private::StepKind::Tag, origin: 1, target: 5 },
+                        coyotes::__private::Step { kind: coyotes::__private::StepKind::ElementClosed, origin: 5, target: 6 },
+                    ]),
+                    Vec::from([
+                        coyotes::__private::Step { kind: coyotes::__private::StepKind::InjectionConfirmed, origin: 7, target: 8 },
+                        coyotes::__private::Step { kind: coyotes::__private::StepKind::Element, origin: 8, target: 9 },
+                        coyotes::__private::Step { kind: coyotes::__private::StepKind::TailElementSolidus, origin: 9, target: 10 },
+                        coyotes::__private::Step { kind: coyotes::__private::StepKind::TailTag, origin: 10, target: 14 },
+                        coyotes::__private::Step { kind: coyotes::__private::StepKind::TailElementClosed, origin: 14, target: 15 },
+                        coyotes::__private::Step { kind: coyotes::__private::StepKind::TextSpace, origin: 15, target: 16 },
+                        coyotes::__private::Step { kind: coyotes::__private::StepKind::Text, origin: 16, target: 19 },
+                        coyotes::__private::Step { kind: coyotes::__private::StepKind::TextSpace, origin: 19, target: 20 },
+                        coyotes::__private::Step { kind: coyotes::__private::StepKind::Element, origin: 20, target: 21 },
+                        coyotes::__private::Step { kind: coyotes::__private::StepKind::Tag, origin: 21, target: 22 },
+                        coyotes::__private::Step { kind: coyotes::__private::StepKind::ElementClosed, origin: 22, target: 23 },
+                        coyotes::__private::Step { kind: coyotes::__private::StepKind::Text, origin: 23, target: 27 },
+                        coyotes::__private::Step { kind: coyotes::__private::StepKind::Element, origin: 27, target: 28 },
+                        coyotes::__private::Step { kind: coyotes::__private::StepKind::TailElementSolidus, origin: 28, target: 29 },
+                        coyotes::__private::Step { kind: coyotes::__private::StepKind::TailTag, origin: 29, target: 30 },
+                        coyotes::__private::Step { kind: coyotes::__private::StepKind::TailElementClosed, origin: 30, target: 31 },
+                        coyotes::__private::Step { kind: coyotes::__private::StepKind::TextSpace, origin: 31, target: 32 },
+                        coyotes::__private::Step { kind: coyotes::__private::StepKind::Text, origin: 32, target: 36 },
+                        coyotes::__private::Step { kind: coyotes::__private::StepKind::TextSpace, origin: 36, target: 37 },
+                    ]),
+                    Vec::from([
+                        coyotes::__private::Step { kind: coyotes::__private::StepKind::InjectionConfirmed, origin: 38, target: 39 },
+                        coyotes::__private::Step { kind: coyotes::__private::StepKind::TextSpace, origin: 39, target: 40 },
+                        coyotes::__private::Step { kind: coyotes::__private::StepKind::Text, origin: 40, target: 44 },
+                        coyotes::__private::Step { kind: coyotes::__private::StepKind::TextSpace, origin: 44, target: 45 },
+                        coyotes::__private::Step { kind: coyotes::__private::StepKind::Element, origin: 45, target: 46 },
+                        coyotes::__private::Step { kind: coyotes::__private::StepKind::Tag, origin: 46, target: 49 },
+                        coyotes::__private::Step { kind: coyotes::__private::StepKind::ElementSpace, origin: 49, target: 50 },
+                        coyotes::__private::Step { kind: coyotes::__private::StepKind::Attr, origin: 50, target: 53 },
+                        coyotes::__private::Step { kind: coyotes::__private::StepKind::AttrSetter, origin: 53, target: 54 },
+                        coyotes::__private::Step { kind: coyotes::__private::StepKind::AttrDoubleQuote, origin: 54, target: 55 },
+                        coyotes::__private::Step { kind: coyotes::__private::StepKind::AttrValueDoubleQuoted, origin: 55, target: 65 },
+                        coyotes::__private::Step { kind: coyotes::__private::StepKind::AttrDoubleQuoteClosed, origin: 65, target: 66 },
+                        coyotes::__private::Step { kind: coyotes::__private::StepKind::EmptyElement, origin: 66, target: 67 },
+                        coyotes::__private::Step { kind: coyotes::__private::StepKind::EmptyElementClosed, origin: 67, target: 68 },
+                    ]),
+                ]),
+                injs: Vec::from([
+                    coyotes::__private::Step { kind: coyotes::__private::StepKind::DescendantInjection, origin: 6, target: 7 },
+                    coyotes::__private::Step { kind: coyotes::__private::StepKind::DescendantInjection, origin: 37, target: 38 },
+                ]),
+            },
+        ),
+        (
+            PAGE_TEMPLATE,
+            coyotes::TemplateSteps {
+                steps: Vec::from([
+                    Vec::from([
+                        coyotes::__private::Step { kind: coyotes::__private::StepKind::Initial, origin: 0, target: 0 },
+                        coyotes::__private::Step { kind: coyotes::__private::StepKind::Element, origin: 0, target: 1 },
+                        coyotes::__private::Step { kind: coyotes::__private::StepKind::Tag, origin: 1, target: 9 },
+                        coyotes::__private::Step { kind: coyotes::__private::StepKind::ElementSpace, origin: 9, target: 10 },
+                        coyotes::__private::Step { kind: coyotes::__private::StepKind::Attr, origin: 10, target: 14 },
+                        coyotes::__private::Step { kind: coyotes::__private::StepKind::ElementClosed, origin: 14, target: 15 },
+                        coyotes::__private::Step { kind: coyotes::__private::StepKind::TextLineSpace, origin: 15, target: 16 },
+                        coyotes::__private::Step { kind: coyotes::__private::StepKind::Element, origin: 16, target: 17 },
+                        coyotes::__private::Step { kind: coyotes::__private::StepKind::Tag, origin: 17, target: 21 },
+                        coyotes::__private::Step { kind: coyotes::__private::StepKind::ElementClosed, origin: 21, target: 22 },
+                        coyotes::__private::Step { kind: coyotes::__private::StepKind::TextLineSpace, origin: 22, target: 23 },
+                        coyotes::__private::Step { kind: coyotes::__private::StepKind::TextSpace, origin: 23, target: 24 },
+                        coyotes::__private::Step { kind: coyotes::__private::StepKind::Element, origin: 24, target: 25 },
+                        coyotes::__private::Step { kind: coyotes::__private::StepKind::Tag, origin: 25, target: 29 },
+                        coyotes::__private::Step { kind: coyotes::__private::StepKind::ElementClosed, origin: 29, target: 30 },
+                        coyotes::__private::Step { kind: coyotes::__private::StepKind::TextLineSpace, origin: 30, target: 31 },
+                        coyotes::__private::Step { kind: coyotes::__private::StepKind::TextSpace, origin: 31, target: 33 },
+                        coyotes::__private::Step { kind: coyotes::__private::StepKind::Element, origin: 33, target: 34 },
+                        coyotes::__private::Step { kind: coyotes::__private::StepKind::Tag, origin: 34, target: 39 },
+                        coyotes::__private::Step { kind: coyotes::__private::StepKind::ElementClosed, origin: 39, target: 40 },
+                    ]),
+                    Vec::from([
+                        coyotes::__private::Step { kind: coyotes::__private::StepKind::InjectionConfirmed, origin: 41, target: 42 },
+                        coyotes::__private::Step { kind: coyotes::__private::StepKind::TextRcdata, origin: 42, target: 42 },
+                        coyotes::__private::Step { kind: coyotes::__private::StepKind::TailTag, origin: 42, target: 49 },
+                        coyotes::__private::Step { kind: coyotes::__private::StepKind::TailElementClosed, origin: 49, target: 50 },
+                        coyotes::__private::Step { kind: coyotes::__private::StepKind::TextLineSpace, origin: 50, target: 51 },
+                        coyotes::__private::Step { kind: coyotes::__private::StepKind::TextSpace, origin: 51, target: 53 },
+                        coyotes::__private::Step { kind: coyotes::__private::StepKind::Element, origin: 53, target: 54 },
+                        coyotes::__private::Step { kind: coyotes::__private::StepKind::Tag, origin: 54, target: 58 },
+                        coyotes::__private::Step { kind: coyotes::__private::StepKind::ElementSpace, origin: 58, target: 59 },
+                        coyotes::__private::Step { kind: coyotes::__private::StepKind::Attr, origin: 59, target: 66 },
+                        coyotes::__private::Step { kind: coyotes::__private::StepKind::AttrSetter, origin: 66, target: 67 },
+                        coyotes::__private::Step { kind: coyotes::__private::StepKind::AttrDoubleQuote, origin: 67, target: 68 },
+                        coyotes::__private::Step { kind: coyotes::__private::StepKind::AttrValueDoubleQuoted, origin: 68, target: 73 },
+                        coyotes::__private::Step { kind: coyotes::__private::StepKind::AttrDoubleQuoteClosed, origin: 73, target: 74 },
+                        coyotes::__private::Step { kind: coyotes::__private::StepKind::ElementClosed, origin: 74, target: 75 },
+                        coyotes::__private::Step { kind: coyotes::__private::StepKind::TextLineSpace, origin: 75, target: 76 },
+                        coyotes::__private::Step { kind: coyotes::__private::StepKind::TextSpace, origin: 76, target: 77 },
+                        coyotes::__private::Step { kind: coyotes::__private::StepKind::Element, origin: 77, target: 78 },
+                        coyotes::__private::Step { kind: coyotes::__private::StepKind::TailElementSolidus, origin: 78, target: 79 },
+                        coyotes::__private::Step { kind: coyotes::__private::StepKind::TailTag, origin: 79, target: 83 },
+                        coyotes::__private::Step { kind: coyotes::__private::StepKind::TailElementClosed, origin: 83, target: 84 },
+                        coyotes::__private::Step { kind: coyotes::__private::StepKind::TextLineSpace, origin: 84, target: 85 },
+                        coyotes::__private::Step { kind: coyotes::__private::StepKind::TextSpace, origin: 85, target: 86 },
+                        coyotes::__private::Step { kind: coyotes::__private::StepKind::Element, origin: 86, target: 87 },
+                        coyotes::__private::Step { kind: coyotes::__private::StepKind::Tag, origin: 87, target: 91 },
+                        coyotes::__private::Step { kind: coyotes::__private::StepKind::ElementSpace, origin: 91, target: 92 },
+                    ]),
+                    Vec::from([
+                        coyotes::__private::Step { kind: coyotes::__private::StepKind::InjectionConfirmed, origin: 93, target: 94 },
+                        coyotes::__private::Step { kind: coyotes::__private::StepKind::ElementClosed, origin: 94, target: 95 },
+                        coyotes::__private::Step { kind: coyotes::__private::StepKind::TextLineSpace, origin: 95, target: 96 },
+                        coyotes::__private::Step { kind: coyotes::__private::StepKind::TextSpace, origin: 96, target: 98 },
+                        coyotes::__private::Step { kind: coyotes::__private::StepKind::Element, origin: 98, target: 99 },
+                        coyotes::__private::Step { kind: coyotes::__private::StepKind::Tag, origin: 99, target: 103 },
+                        coyotes::__private::Step { kind: coyotes::__private::StepKind::ElementClosed, origin: 103, target: 104 },
+                        coyotes::__private::Step { kind: coyotes::__private::StepKind::TextLineSpace, origin: 104, target: 105 },
+                        coyotes::__private::Step { kind: coyotes::__private::StepKind::TextSpace, origin: 105, target: 108 },
+                        coyotes::__private::Step { kind: coyotes::__private::StepKind::Element, origin: 108, target: 109 },
+                        coyotes::__private::Step { kind: coyotes::__private::StepKind::Tag, origin: 109, target: 111 },
+                        coyotes::__private::Step { kind: coyotes::__private::StepKind::ElementClosed, origin: 111, target: 112 },
+                        coyotes::__private::Step { kind: coyotes::__private::StepKind::Text, origin: 112, target: 115 },
+                        coyotes::__private::Step { kind: coyotes::__private::StepKind::TextSpace, origin: 115, target: 116 },
+                        coyotes::__private::Step { kind: coyotes::__private::StepKind::Text, origin: 116, target: 123 },
+                        coyotes::__private::Step { kind: coyotes::__private::StepKind::Element, origin: 123, target: 124 },
+                        coyotes::__private::Step { kind: coyotes::__private::StepKind::TailElementSolidus, origin: 124, target: 125 },
+                        coyotes::__private::Step { kind: coyotes::__private::StepKind::TailTag, origin: 125, target: 127 },
+                        coyotes::__private::Step { kind: coyotes::__private::StepKind::TailElementClosed, origin: 127, target: 128 },
+                        coyotes::__private::Step { kind: coyotes::__private::StepKind::TextLineSpace, origin: 128, target: 129 },
+                        coyotes::__private::Step { kind: coyotes::__private::StepKind::TextSpace, origin: 129, target: 132 },
+                    ]),
+                    Vec::from([
+                        coyotes::__private::Step { kind: coyotes::__private::StepKind::InjectionConfirmed, origin: 133, target: 134 },
+                        coyotes::__private::Step { kind: coyotes::__private::StepKind::TextLineSpace, origin: 134, target: 135 },
+                        coyotes::__private::Step { kind: coyotes::__private::StepKind::TextSpace, origin: 135, target: 138 },
+                        coyotes::__private::Step { kind: coyotes::__private::StepKind::Element, origin: 138, target: 139 },
+                        coyotes::__private::Step { kind: coyotes::__private::StepKind::Tag, origin: 139, target: 141 },
+                        coyotes::__private::Step { kind: coyotes::__private::StepKind::ElementClosed, origin: 141, target: 142 },
+                        coyotes::__private::Step { kind: coyotes::__private::StepKind::TextLineSpace, origin: 142, target: 143 },
+                        coyotes::__private::Step { kind: coyotes::__private::StepKind::TextSpace, origin: 143, target: 147 },
+                        coyotes::__private::Step { kind: coyotes::__private::StepKind::Element, origin: 147, target: 148 },
+                        coyotes::__private::Step { kind: coyotes::__private::StepKind::Tag, origin: 148, target: 150 },
+                        coyotes::__private::Step { kind: coyotes::__private::StepKind::ElementClosed, origin: 150, target: 151 },
+                        coyotes::__private::Step { kind: coyotes::__private::StepKind::Text, origin: 151, target: 154 },
+                        coyotes::__private::Step { kind: coyotes::__private::StepKind::Element, origin: 154, target: 155 },
+                        coyotes::__private::Step { kind: coyotes::__private::StepKind::TailElementSolidus, origin: 155, target: 156 },
+                        coyotes::__private::Step { kind: coyotes::__private::StepKind::TailTag, origin: 156, target: 158 },
+                        coyotes::__private::Step { kind: coyotes::__private::StepKind::TailElementClosed, origin: 158, target: 159 },
+                        coyotes::__private::Step { kind: coyotes::__private::StepKind::TextLineSpace, origin: 159, target: 160 },
+                        coyotes::__private::Step { kind: coyotes::__private::StepKind::TextSpace, origin: 160, target: 164 },
+                        coyotes::__private::Step { kind: coyotes::__private::StepKind::Element, origin: 164, target: 165 },
+                        coyotes::__private::Step { kind: coyotes::__private::StepKind::Tag, origin: 165, target: 167 },
+                        coyotes::__private::Step { kind: coyotes::__private::StepKind::ElementClosed, origin: 167, target: 168 },
+                        coyotes::__private::Step { kind: coyotes::__private::StepKind::Text, origin: 168, target: 171 },
+                        coyotes::__private::Step { kind: coyotes::__private::StepKind::Element, origin: 171, target: 172 },
+                        coyotes::__private::Step { kind: coyotes::__private::StepKind::TailElementSolidus, origin: 172, target: 173 },
+                        coyotes::__private::Step { kind: coyotes::__private::StepKind::TailTag, origin: 173, target: 175 },
+                        coyotes::__private::Step { kind: coyotes::__private::StepKind::TailElementClosed, origin: 175, target: 176 },
+                        coyotes::__private::Step { kind: coyotes::__private::StepKind::TextLineSpace, origin: 176, target: 177 },
+                        coyotes::__private::Step { kind: coyotes::__private::StepKind::TextSpace, origin: 177, target: 180 },
+                        coyotes::__private::Step { kind: coyotes::__private::StepKind::Element, origin: 180, target: 181 },
+                        coyotes::__private::Step { kind: coyotes::__private::StepKind::TailElementSolidus, origin: 181, target: 182 },
+                        coyotes::__private::Step { kind: coyotes::__private::StepKind::TailTag, origin: 182, target: 184 },
+                        coyotes::__private::Step { kind: coyotes::__private::StepKind::TailElementClosed, origin: 184, target: 185 },
+                        coyotes::__private::Step { kind: coyotes::__private::StepKind::TextLineSpace, origin: 185, target: 186 },
+                        coyotes::__private::Step { kind: coyotes::__private::StepKind::TextSpace, origin: 186, target: 188 },
+                        coyotes::__private::Step { kind: coyotes::__private::StepKind::Element, origin: 188, target: 189 },
+                        coyotes::__private::Step { kind: coyotes::__private::StepKind::TailElementSolidus, origin: 189, target: 190 },
+                        coyotes::__private::Step { kind: coyotes::__private::StepKind::TailTag, origin: 190, target: 194 },
+                        coyotes::__private::Step { kind: coyotes::__private::StepKind::TailElementClosed, origin: 194, target: 195 },
+                        coyotes::__private::Step { kind: coyotes::__private::StepKind::TextLineSpace, origin: 195, target: 196 },
+                        coyotes::__private::Step { kind: coyotes::__private::StepKind::TextSpace, origin: 196, target: 198 },
+                        coyotes::__private::Step { kind: coyotes::__private::StepKind::Element, origin: 198, target: 199 },
+                        coyotes::__private::Step { kind: coyotes::__private::StepKind::Tag, origin: 199, target: 205 },
+                        coyotes::__private::Step { kind: coyotes::__private::StepKind::ElementClosed, origin: 205, target: 206 },
+                        coyotes::__private::Step { kind: coyotes::__private::StepKind::Text, origin: 206, target: 208 },
+                        coyotes::__private::Step { kind: coyotes::__private::StepKind::Element, origin: 208, target: 209 },
+                        coyotes::__private::Step { kind: coyotes::__private::StepKind::TailElementSolidus, origin: 209, target: 210 },
+                        coyotes::__private::Step { kind: coyotes::__private::StepKind::TailTag, origin: 210, target: 216 },
+                        coyotes::__private::Step { kind: coyotes::__private::StepKind::TailElementClosed, origin: 216, target: 217 },
+                        coyotes::__private::Step { kind: coyotes::__private::StepKind::TextLineSpace, origin: 217, target: 218 },
+                        coyotes::__private::Step { kind: coyotes::__private::StepKind::TextSpace, origin: 218, target: 219 },
+                        coyotes::__private::Step { kind: coyotes::__private::StepKind::Element, origin: 219, target: 220 },
+                        coyotes::__private::Step { kind: coyotes::__private::StepKind::TailElementSolidus, origin: 220, target: 221 },
+                        coyotes::__private::Step { kind: coyotes::__private::StepKind::TailTag, origin: 221, target: 225 },
+                        coyotes::__private::Step { kind: coyotes::__private::StepKind::TailElementClosed, origin: 225, target: 226 },
+                        coyotes::__private::Step { kind: coyotes::__private::StepKind::TextLineSpace, origin: 226, target: 227 },
+                        coyotes::__private::Step { kind: coyotes::__private::StepKind::Element, origin: 227, target: 228 },
+                        coyotes::__private::Step { kind: coyotes::__private::StepKind::TailElementSolidus, origin: 228, target: 229 },
+                        coyotes::__private::Step { kind: coyotes::__private::StepKind::TailTag, origin: 229, target: 233 },
+                        coyotes::__private::Step { kind: coyotes::__private::StepKind::TailElementClosed, origin: 233, target: 234 },
+                        coyotes::__private::Step { kind: coyotes::__private::StepKind::TextLineSpace, origin: 234, target: 235 },
+                    ]),
+                ]),
+                injs: Vec::from([
+                    coyotes::__private::Step { kind: coyotes::__private::StepKind::DescendantInjection, origin: 40, target: 41 },
+                    coyotes::__private::Step { kind: coyotes::__private::StepKind::AttrMapInjection, origin: 92, target: 93 },
+                    coyotes::__private::Step { kind: coyotes::__private::StepKind::DescendantInjection, origin: 132, target: 133 },
+                ]),
+            },
+        ),
+        (
+            RAW_TEXT_TEMPLATE,
+            coyotes::TemplateSteps {
+                steps: Vec::from([
+                    Vec::from([
+                        coyotes::__private::Step { kind: coyotes::__private::StepKind::Initial, origin: 0, target: 0 },
+                        coyotes::__private::Step { kind: coyotes::__private::StepKind::Element, origin: 0, target: 1 },
+                        coyotes::__private::Step { kind: coyotes::__private::StepKind::Tag, origin: 1, target: 7 },
+                        coyotes::__private::Step { kind: coyotes::__private::StepKind::ElementClosed, origin: 7, target: 8 },
+                        coyotes::__private::Step { kind: coyotes::__private::StepKind::TextAlt, origin: 8, target: 30 },
+                        coyotes::__private::Step { kind: coyotes::__private::StepKind::TailTag, origin: 30, target: 38 },
+                        coyotes::__private::Step { kind: coyotes::__private::StepKind::TailElementClosed, origin: 38, target: 39 },
+                        coyotes::__private::Step { kind: coyotes::__private::StepKind::TextLineSpace, origin: 39, target: 40 },
+                        coyotes::__private::Step { kind: coyotes::__private::StepKind::Element, origin: 40, target: 41 },
+                        coyotes::__private::Step { kind: coyotes::__private::StepKind::Tag, origin: 41, target: 46 },
+                        coyotes::__private::Step { kind: coyotes::__private::StepKind::ElementClosed, origin: 46, target: 47 },
+                        coyotes::__private::Step { kind: coyotes::__private::StepKind::TextAlt, origin: 47, target: 64 },
+                        coyotes::__private::Step { kind: coyotes::__private::StepKind::TailTag, origin: 64, target: 71 },
+                        coyotes::__private::Step { kind: coyotes::__private::StepKind::TailElementClosed, origin: 71, target: 72 },
+                        coyotes::__private::Step { kind: coyotes::__private::StepKind::TextLineSpace, origin: 72, target: 73 },
+                        coyotes::__private::Step { kind: coyotes::__private::StepKind::Element, origin: 73, target: 74 },
+                        coyotes::__private::Step { kind: coyotes::__private::StepKind::Tag, origin: 74, target: 82 },
+                        coyotes::__private::Step { kind: coyotes::__private::StepKind::ElementClosed, origin: 82, target: 83 },
+                        coyotes::__private::Step { kind: coyotes::__private::StepKind::TextRcdata, origin: 83, target: 85 },
+                    ]),
+                    Vec::from([
+                        coyotes::__private::Step { kind: coyotes::__private::StepKind::InjectionConfirmed, origin: 86, target: 87 },
+                        coyotes::__private::Step { kind: coyotes::__private::StepKind::TextRcdata, origin: 87, target: 87 },
+                        coyotes::__private::Step { kind: coyotes::__private::StepKind::TailTag, origin: 87, target: 97 },
+                        coyotes::__private::Step { kind: coyotes::__private::StepKind::TailElementClosed, origin: 97, target: 98 },
+                        coyotes::__private::Step { kind: coyotes::__private::StepKind::TextLineSpace, origin: 98, target: 99 },
+                        coyotes::__private::Step { kind: coyotes::__private::StepKind::Element, origin: 99, target: 100 },
+                        coyotes::__private::Step { kind: coyotes::__private::StepKind::Tag, origin: 100, target: 103 },
+                        coyotes::__private::Step { kind: coyotes::__private::StepKind::TextAlt, origin: 103, target: 114 },
+                        coyotes::__private::Step { kind: coyotes::__private::StepKind::TailTag, origin: 114, target: 116 },
+                        coyotes::__private::Step { kind: coyotes::__private::StepKind::TailElementClosed, origin: 116, target: 117 },
+                        coyotes::__private::Step { kind: coyotes::__private::StepKind::TextLineSpace, origin: 117, target: 118 },
+                    ]),
+                ]),
+                injs: Vec::from([
+                    coyotes::__private::Step { kind: coyotes::__private::StepKind::DescendantInjection, origin: 85, target: 86 },
+                ]),
+            },
+        ),
+    ])
+}
+
+pub mod compiled {
+    pub fn card(writer: &mut coyotes::__private::StepWriter, chunk: usize) -> Result<(), coyotes::Errors> {
+        match chunk {
+            0 => match writer.get_static_frame(0) {
+                Some(frame) => {
+                    writer.push_element("section");
+                    writer.push_element_space();
+                    writer.push_attr("class");
+                    writer.push_attr_value_double_quoted("card");
+                    if let Err(e) = writer.close_element() {
+                        return Err(e);
+                    }
+                    writer.push_static(&frame, &["\n\t", "<h2>"]);
+                    writer.set_static_text_format(&frame, 1, coyotes::__private::TextFormat::LineSpace);
+                    writer.push_static_element("h2", coyotes::__private::TextFormat::Text);
+                }
+                _ => {
+                    writer.push_element("section");
+                    writer.push_element_space();
+                    writer.push_attr("class");
+                    writer.push_attr_value_double_quoted("card");
+                    if let Err(e) = writer.close_element() {
+                        return Err(e);
+                    }
+                    writer.push_text_line_space("\n");
+                    writer.push_text_space("\t");
+                    writer.push_element("h2");
+                    if let Err(e) = writer.close_element() {
+                        return Err(e);
+                    }
+                }
+            },
+            1 => match writer.get_static_frame(2) {
+                Some(frame) => {
+                    writer.pop_element("h2");
+                    writer.close_tail_tag();
+                    writer.push_static(&frame, &["\n\t", "<p>a few words with spaces\n\t\t", "and lines</p><pre>\n  keep\n\tthis\n</pre>"]);
+                    writer.set_static_text_format(&frame, 1, coyotes::__private::TextFormat::LineSpace);
+                }
+                _ => {
+                    writer.pop_element("h2");
+                    writer.close_tail_tag();
+                    writer.push_text_line_space("\n");
+                    writer.push_text_space("\t");
+                    writer.push_element("p");
+                    if let Err(e) = writer.close_element() {
+                        return Err(e);
+                    }
+                    writer.push_text("a");
+                    writer.push_text_space(" ");
+                    writer.push_text("few");
+                    writer.push_text_space(" ");
+                    writer.push_text("words");
+                    writer.push_text_space("   ");
+                    writer.push_text("with");
+                    writer.push_text_space(" ");
+                    writer.push_text("spaces");
+                    writer.push_text_line_space("\n");
+                    writer.push_text_space("\t\t");
+                    writer.push_text("and");
+                    writer.push_text_space(" ");
+                    writer.push_text("lines");
+                    writer.pop_element("p");
+                    writer.close_tail_tag();
+                    writer.push_text_line_space("\n");
+                    writer.push_text_space("\t");
+                    writer.push_element("pre");
+                    if let Err(e) = writer.close_element() {
+                        return Err(e);
+                    }
+                    writer.push_text_line_space("\n");
+                    writer.push_text_space("  ");
+                    writer.push_text("keep");
+                    writer.push_text_line_space("\n");
+                    writer.push_text_space("\t");
+                    writer.push_text("this");
+                    writer.push_text_line_space("\n");
+                    writer.pop_element("pre");
+                    writer.close_tail_tag();
+                    writer.push_text_line_space("\n");
+                    writer.push_text_space("\t");
+                }
+            },
+            2 => match writer.get_static_frame(1) {
+                Some(frame) => {
+                    writer.push_text_line_space("\n");
+                    writer.push_element("input");
+                    writer.push_element_space();
+                    writer.push_attr("type");
+                    writer.push_attr_value_double_quoted("text");
+                    writer.push_element_line_space();
+                    writer.push_element_space();
+                    writer.push_attr("name");
+                    writer.push_attr_value_double_quoted("howl");
+                    if let Err(e) = writer.close_element() {
+                        return Err(e);
+                    }
+                    writer.push_static(&frame, &["\n", "</section>"]);
+                    writer.truncate_static(&frame, 1);
+                    writer.set_static_text_format(&frame, 0, coyotes::__private::TextFormat::LineSpace);
+                }
+                _ => {
+                    writer.push_text_line_space("\n");
+                    writer.push_text_space("\t");
+                    writer.push_element("input");
+                    writer.push_element_space();
+                    writer.push_attr("type");
+                    writer.push_attr_value_double_quoted("text");
+                    writer.push_element_line_space();
+                    writer.push_element_space();
+                    writer.push_attr("name");
+                    writer.push_attr_value_double_quoted("howl");
+                    if let Err(e) = writer.close_element() {
+                        return Err(e);
+                    }
+                    writer.push_text_line_space("\n");
+                    writer.pop_element("section");
+                    writer.close_tail_tag();
+                    writer.push_text_line_space("\n");
+                }
+            },
+            _ => {}
+        }
+
+        Ok(())
+    }
+
+    pub fn greeting(writer: &mut coyotes::__private::StepWriter, chunk: usize) -> Result<(), coyotes::Errors> {
+        match chunk {
+            0 => {
+                writer.push_element("article");
+                writer.push_element_space();
+            }
+            1 => match writer.get_static_frame(1) {
+                Some(frame) => {
+                    if let Err(e) = writer.close_element() {
+                        return Err(e);
+                    }
+                    writer.push_static(&frame, &["\n\t", "<p>hai"]);
+                    writer.set_static_text_format(&frame, 1, coyotes::__private::TextFormat::LineSpace);
+                    writer.push_static_element("p", coyotes::__private::TextFormat::Space);
+                }
+                _ => {
+                    if let Err(e) = writer.close_element() {
+                        return Err(e);
+                    }
+                    writer.push_text_line_space("\n");
+                    writer.push_text_space("\t");
+                    writer.push_element("p");
+                    if let Err(e) = writer.close_element() {
+                        return Err(e);
+                    }
+                    writer.push_text("hai");
+                    writer.push_text_space(" ");
+                }
+            },
+            2 => match writer.get_static_frame(2) {
+                Some(frame) => {
+                    writer.push_text_space(" ");
+                    writer.push_text(":3");
+                    writer.push_static(&frame, &["</p>\n", "</article>"]);
+                    writer.truncate_static(&frame, 1);
+                    writer.set_static_text_format(&frame, 0, coyotes::__private::TextFormat::Text);
+                }
+                _ => {
+                    writer.push_text_space(" ");
+                    writer.push_text(":3");
+                    writer.pop_element("p");
+                    writer.close_tail_tag();
+                    writer.push_text_line_space("\n");
+                    writer.pop_element("article");
+                    writer.close_tail_tag();
+                }
+            },
+            _ => {}
+        }
+
+        Ok(())
+    }
+
+    pub fn inline(writer: &mut coyotes::__private::StepWriter, chunk: usize) -> Result<(), coyotes::Errors> {
+        match chunk {
+            0 => {
+                writer.push_element("span");
+                if let Err(e) = writer.close_element() {
+                    return Err(e);
+                }
+            }
+            1 => match writer.get_static_frame(1) {
+                Some(frame) => {
+                    writer.pop_element("span");
+                    writer.close_tail_tag();
+                    writer.push_static(&frame, &[" and <b>bold</b> text"]);
+                    writer.set_static_text_format(&frame, 0, coyotes::__private::TextFormat::Space);
+                }
+                _ => {
+                    writer.pop_element("span");
+                    writer.close_tail_tag();
+                    writer.push_text_space(" ");
+                    writer.push_text("and");
+                    writer.push_text_space(" ");
+                    writer.push_element("b");
+                    if let Err(e) = writer.close_element() {
+                        return Err(e);
+                    }
+                    writer.push_text("bold");
+                    writer.pop_element("b");
+                    writer.close_tail_tag();
+                    writer.push_text_space(" ");
+                    writer.push_text("text");
+                    writer.push_text_space(" ");
+                }
+            },
+            2 => match writer.get_static_frame(0) {
+                Some(frame) => {
+                    writer.push_text_space(" ");
+                    writer.push_text("more");
+                    writer.push_static(&frame, &[" <img src=\"coyote.png\">"]);
+                }
+                _ => {
+                    writer.push_text_space(" ");
+                    writer.push_text("more");
+                    writer.push_text_space(" ");
+                    writer.push_element("img");
+                    writer.push_element_space();
+                    writer.push_attr("src");
+                    writer.push_attr_value_double_quoted("coyote.png");
+                    if let Err(e) = writer.close_empty_element() {
+                        return Err(e);
+                    }
+                }
+            },
+            _ => {}
+        }
+
+        Ok(())
+    }
+
+    pub fn page(writer: &mut coyotes::__private::StepWriter, chunk: usize) -> Result<(), coyotes::Errors> {
+        match chunk {
+            0 => match writer.get_static_frame(0) {
+                Some(frame) => {
+                    writer.push_element("!DOCTYPE");
+                    writer.push_element_space();
+                    writer.push_attr("html");
+                    if let Err(e) = writer.close_element() {
+                        return Err(e);
+                    }
+                    writer.push_static(&frame, &["\n", "<html>\n\t", "<head>\n\t\t", "<title>"]);
+                    writer.set_static_text_format(&frame, 0, coyotes::__private::TextFormat::LineSpace);
+                    writer.push_static_element("html", coyotes::__private::TextFormat::LineSpace);
+                    writer.push_static_element("head", coyotes::__private::TextFormat::LineSpace);
+                    writer.push_static_element("title", coyotes::__private::TextFormat::Text);
+                }
+                _ => {
+                    writer.push_element("!DOCTYPE");
+                    writer.push_element_space();
+                    writer.push_attr("html");
+                    if let Err(e) = writer.close_element() {
+                        return Err(e);
+                    }
+                    writer.push_text_line_space("\n");
+                    writer.push_element("html");
+                    if let Err(e) = writer.close_element() {
+                        return Err(e);
+                    }
+                    writer.push_text_line_space("\n");
+                    writer.push_text_space("\t");
+                    writer.push_element("head");
+                    if let Err(e) = writer.close_element() {
+                        return Err(e);
+                    }
+                    writer.push_text_line_space("\n");
+                    writer.push_text_space("\t\t");
+                    writer.push_element("title");
+                    if let Err(e) = writer.close_element() {
+                        return Err(e);
+                    }
+                }
+            },
+            1 => match writer.get_static_frame(3) {
+                Some(frame) => {
+                    writer.push_static(&frame, &["</title>\n\t\t", "<meta charset=\"utf-8\">\n\t", "</head>"]);
+                    writer.truncate_static(&frame, 2);
+                    writer.push_element("body");
+                    writer.push_element_space();
+                }
+                _ => {
+                    writer.push_text("");
+                    writer.pop_element("</title");
+                    writer.close_tail_tag();
+                    writer.push_text_line_space("\n");
+                    writer.push_text_space("\t\t");
+                    writer.push_element("meta");
+                    writer.push_element_space();
+                    writer.push_attr("charset");
+                    writer.push_attr_value_double_quoted("utf-8");
+                    if let Err(e) = writer.close_element() {
+                        return Err(e);
+                    }
+                    writer.push_text_line_space("\n");
+                    writer.push_text_space("\t");
+                    writer.pop_element("head");
+                    writer.close_tail_tag();
+                    writer.push_text_line_space("\n");
+                    writer.push_text_space("\t");
+                    writer.push_element("body");
+                    writer.push_element_space();
+                }
+            },
+            2 => match writer.get_static_frame(2) {
+                Some(frame) => {
+                    if let Err(e) = writer.close_element() {
+                        return Err(e);
+                    }
+                    writer.push_static(&frame, &["\n\t\t", "<main>\n\t\t\t", "<h1>hai coyotes</h1>"]);
+                    writer.set_static_text_format(&frame, 2, coyotes::__private::TextFormat::LineSpace);
+                    writer.push_static_element("main", coyotes::__private::TextFormat::LineSpace);
+                }
+                _ => {
+                    if let Err(e) = writer.close_element() {
+                        return Err(e);
+                    }
+                    writer.push_text_line_space("\n");
+                    writer.push_text_space("\t\t");
+                    writer.push_element("main");
+                    if let Err(e) = writer.close_element() {
+                        return Err(e);
+                    }
+                    writer.push_text_line_space("\n");
+                    writer.push_text_space("\t\t\t");
+                    writer.push_element("h1");
+                    if let Err(e) = writer.close_element() {
+                        return Err(e);
+                    }
+                    writer.push_text("hai");
+                    writer.push_text_space(" ");
+                    writer.push_text("coyotes");
+                    writer.pop_element("h1");
+                    writer.close_tail_tag();
+                    writer.push_text_line_space("\n");
+                    writer.push_text_space("\t\t\t");
+                }
+            },
+            3 => match writer.get_static_frame(3) {
+                Some(frame) => {
+                    writer.push_text_line_space("\n");
+                    writer.push_element("ul");
+                    if let Err(e) = writer.close_element() {
+                        return Err(e);
+                    }
+                    writer.push_static(&frame, &["\n\t\t\t\t", "<li>one</li>\n\t\t\t\t", "<li>two</li>\n\t\t\t", "</ul>\n\t\t", "</main>\n\t\t", "<footer>:3</footer>\n\t", "</body>\n", "</html>"]);
+                    writer.truncate_static(&frame, 1);
+                }
+                _ => {
+                    writer.push_text_line_space("\n");
+                    writer.push_text_space("\t\t\t");
+                    writer.push_element("ul");
+                    if let Err(e) = writer.close_element() {
+                        return Err(e);
+                    }
+                    writer.push_text_line_space("\n");
+                    writer.push_text_space("\t\t\t\t");
+                    writer.push_element("li");
+                    if let Err(e) = writer.close_element() {
+                        return Err(e);
+                    }
+                    writer.push_text("one");
+                    writer.pop_element("li");
+                    writer.close_tail_tag();
+                    writer.push_text_line_space("\n");
+                    writer.push_text_space("\t\t\t\t");
+                    writer.push_element("li");
+                    if let Err(e) = writer.close_element() {
+                        return Err(e);
+                    }
+                    writer.push_text("two");
+                    writer.pop_element("li");
+                    writer.close_tail_tag();
+                    writer.push_text_line_space("\n");
+                    writer.push_text_space("\t\t\t");
+                    writer.pop_element("ul");
+                    writer.close_tail_tag();
+                    writer.push_text_line_space("\n");
+                    writer.push_text_space("\t\t");
+                    writer.pop_element("main");
+                    writer.close_tail_tag();
+                    writer.push_text_line_space("\n");
+                    writer.push_text_space("\t\t");
+                    writer.push_element("footer");
+                    if let Err(e) = writer.close_element() {
+                        return Err(e);
+                    }
+                    writer.push_text(":3");
+                    writer.pop_element("footer");
+                    writer.close_tail_tag();
+                    writer.push_text_line_space("\n");
+                    writer.push_text_space("\t");
+                    writer.pop_element("body");
+                    writer.close_tail_tag();
+                    writer.push_text_line_space("\n");
+                    writer.pop_element("html");
+                    writer.close_tail_tag();
+                    writer.push_text_line_space("\n");
+                }
+            },
+            _ => {}
+        }
+
+        Ok(())
+    }
+
+    pub fn raw_text(writer: &mut coyotes::__private::StepWriter, chunk: usize) -> Result<(), coyotes::Errors> {
+        match chunk {
+            0 => match writer.get_static_frame(0) {
+                Some(frame) => {
+                    writer.push_element("script");
+                    if let Err(e) = writer.close_element() {
+                        return Err(e);
+                    }
+                    writer.push_static(&frame, &["\n", "const howl = \"<p>\";</script>\n", "<style>p { color: red; }</style>\n", "<textarea>\n\t"]);
+                    writer.truncate_static(&frame, 1);
+                    writer.set_static_text_format(&frame, 0, coyotes::__private::TextFormat::LineSpace);
+                    writer.push_static_element("textarea", coyotes::__private::TextFormat::Text);
+                }
+                _ => {
+                    writer.push_element("script");
+                    if let Err(e) = writer.close_element() {
+                        return Err(e);
+                    }
+                    writer.push_alt_text("\n\tconst howl = \"<p>\";\n");
+                    writer.pop_element("</script");
+                    writer.close_tail_tag();
+                    writer.push_text_line_space("\n");
+                    writer.push_element("style");
+                    if let Err(e) = writer.close_element() {
+                        return Err(e);
+                    }
+                    writer.push_alt_text("p { color: red; }");
+                    writer.pop_element("</style");
+                    writer.close_tail_tag();
+                    writer.push_text_line_space("\n");
+                    writer.push_element("textarea");
+                    if let Err(e) = writer.close_element() {
+                        return Err(e);
+                    }
+                    writer.push_text("\n\t");
+                }
+            },
+            1 => match writer.get_static_frame(1) {
+                Some(frame) => {
+                    writer.push_static(&frame, &["</textarea>\n", "<!-- a comment -->"]);
+                    writer.truncate_static(&frame, 1);
+                }
+                _ => {
+                    writer.push_text("");
+                    writer.pop_element("</textarea");
+                    writer.close_tail_tag();
+                    writer.push_text_line_space("\n");
+                    writer.push_element("!--");
+                    writer.push_alt_text(" a comment ");
+                    writer.pop_element("--");
+                    writer.close_tail_tag();
+                    writer.push_text_line_space("\n");
+                }
+            },
+            _ => {}
+        }
+
+        Ok(())
+    }
+}
+
+pub fn get_compiled_steps() -> Vec<(&'static str, &'static str, coyotes::__private::CompiledSteps)> {
+    Vec::from([
+        (
+            CARD_TEMPLATE,
+            "271a9045",
+            compiled::card as coyotes::__private::CompiledSteps,
+        ),
+        (
+            GREETING_TEMPLATE,
+            "71a0db87",
+            compiled::greeting as coyotes::__private::CompiledSteps,
+        ),
+        (
+            INLINE_TEMPLATE,
+            "49db1764",
+            compiled::inline as coyotes::__private::CompiledSteps,
+        ),
+        (
+            PAGE_TEMPLATE,
+            "7d4d4d38",
+            compiled::page as coyotes::__private::CompiledSteps,
+        ),
+        (
+            RAW_TEXT_TEMPLATE,
+            "7115976b",
+            compiled::raw_text as coyotes::__private::CompiledSteps,
+        ),
+    ])
+}
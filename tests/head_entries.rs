@@ -0,0 +1,148 @@
+use coyotes::{Component, Html, head, list, memo, meta, portal, slot, stylesheet, text, tmpl};
+
+fn document(body: Component) -> Component {
+    tmpl(
+        "
+        <html>
+            <head>
+                <meta charset=\"utf-8\">
+                {}
+            </head>
+            <body>
+                {}
+            </body>
+        </html>
+        ",
+        [slot("head"), body],
+    )
+}
+
+fn title(title_str: &str) -> Component {
+    head("title", tmpl("<title>{}</title>", [text(title_str)]))
+}
+
+fn product_card(name: &str) -> Component {
+    list([
+        title(name),
+        head(
+            "og:title",
+            meta()
+                .attr("property", "og:title")
+                .attr("content", name)
+                .into(),
+        ),
+        tmpl("<article>{}</article>", [text(name)]),
+    ])
+}
+
+#[test]
+fn head_entries_render_at_head_slot() {
+    let template = document(product_card("coyote plush"));
+    let expected = "<html>\n\t<head>\n\t\t<meta charset=\"utf-8\">\n\t\t<title>coyote plush</title>\n\t\t<meta property=\"og:title\" content=\"coyote plush\">\n\t</head>\n\t<body>\n\t\t<article>coyote plush</article>\n\t</body>\n</html>";
+
+    let mut html = Html::new();
+    let results = html.render(&template);
+
+    assert_eq!(Ok(expected.to_string()), results);
+}
+
+#[test]
+fn later_head_entries_replace_earlier_entries() {
+    let template = document(list([title("home"), product_card("coyote plush")]));
+    let expected = "<html>\n\t<head>\n\t\t<meta charset=\"utf-8\">\n\t\t<title>coyote plush</title>\n\t\t<meta property=\"og:title\" content=\"coyote plush\">\n\t</head>\n\t<body>\n\t\t<article>coyote plush</article>\n\t</body>\n</html>";
+
+    let mut html = Html::new();
+    let results = html.render(&template);
+
+    assert_eq!(Ok(expected.to_string()), results);
+}
+
+#[test]
+fn portals_render_at_named_slots() {
+    let template = tmpl(
+        "<main>{}</main><footer>{}</footer>",
+        [
+            list([portal("footer", "copyright", text("2026")), text("hai :3")]),
+            slot("footer"),
+        ],
+    );
+    let expected = "<main>hai :3</main><footer>2026</footer>";
+
+    let mut html = Html::new();
+    let results = html.render(&template);
+
+    assert_eq!(Ok(expected.to_string()), results);
+}
+
+#[test]
+fn portals_without_slots_are_not_rendered() {
+    let template = tmpl("<p>{}</p>", [list([title("home"), text("hai :3")])]);
+    let expected = "<p>hai :3</p>";
+
+    let mut html = Html::new();
+    let results = html.render(&template);
+
+    assert_eq!(Ok(expected.to_string()), results);
+}
+
+#[test]
+fn memo_replays_head_entries() {
+    let expected = "<html>\n\t<head>\n\t\t<meta charset=\"utf-8\">\n\t\t<title>coyote plush</title>\n\t\t<meta property=\"og:title\" content=\"coyote plush\">\n\t</head>\n\t<body>\n\t\t<article>coyote plush</article>\n\t</body>\n</html>";
+
+    let mut html = Html::new();
+    let _ = html.render(&document(memo("card", product_card("coyote plush"))));
+    let results = html.render(&document(memo("card", product_card("coyote plush"))));
+
+    assert_eq!(Ok(expected.to_string()), results);
+}
+
+#[test]
+fn portals_inside_portals_are_rendered() {
+    let template = document(list([
+        portal("footer", "copyright", list([title("nested"), text("2026")])),
+        tmpl("<footer>{}</footer>", [slot("footer")]),
+    ]));
+    let expected = "<html>\n\t<head>\n\t\t<meta charset=\"utf-8\">\n\t\t<title>nested</title>\n\t</head>\n\t<body>\n\t\t<footer>2026</footer>\n\t</body>\n</html>";
+
+    let mut html = Html::new();
+    let results = html.render(&template);
+
+    assert_eq!(Ok(expected.to_string()), results);
+}
+
+#[test]
+fn assets_inside_head_entries_are_rendered() {
+    let template = document(head(
+        "styles",
+        list([
+            meta().attr("name", "theme-color").into(),
+            stylesheet("/a.css"),
+        ]),
+    ));
+    let expected = "<html>\n\t<head>\n\t\t<meta charset=\"utf-8\">\n\t\t<meta name=\"theme-color\">\n\t\t<link rel=\"stylesheet\" href=\"/a.css\">\n\t</head>\n\t<body>\n\t</body>\n</html>";
+
+    let mut html = Html::new();
+    let results = html.render(&template);
+
+    assert_eq!(Ok(expected.to_string()), results);
+}
+
+#[test]
+fn slots_inside_portal_entries_are_rendered() {
+    let template = tmpl(
+        "<main>{}</main>{}",
+        [
+            list([
+                portal("aside", "aside", tmpl("<aside>{}</aside>", [slot("links")])),
+                portal("links", "home", text("home")),
+            ]),
+            slot("aside"),
+        ],
+    );
+    let expected = "<main></main><aside>home</aside>";
+
+    let mut html = Html::new();
+    let results = html.render(&template);
+
+    assert_eq!(Ok(expected.to_string()), results);
+}
@@ -0,0 +1,90 @@
+use coyotes::{Component, Html, HtmlOnly, island, island_manifest, list, text, tmpl};
+
+fn counter(count: usize) -> Component {
+    island(
+        "counter",
+        &format!("{{\"count\":{}}}", count),
+        tmpl("<button>{}</button>", [text(&count.to_string())]),
+    )
+}
+
+fn document(body: Component) -> Component {
+    tmpl("<body>{}{}</body>", [body, island_manifest()])
+}
+
+#[test]
+fn island_renders_marker_element() {
+    let template = counter(1);
+    let expected = "<coyote-island data-island=\"counter\" data-props=\"{&quot;count&quot;:1}\"><button>1</button></coyote-island>";
+
+    let mut html = Html::new();
+    let results = html.render(&template);
+
+    assert_eq!(Ok(expected.to_string()), results);
+}
+
+#[test]
+fn island_manifest_lists_islands_once() {
+    let template = document(list([
+        counter(1),
+        island("search", "{}", tmpl("<input>", [])),
+        counter(2),
+    ]));
+    let expected = "<body><coyote-island data-island=\"counter\" data-props=\"{&quot;count&quot;:1}\"><button>1</button></coyote-island><coyote-island data-island=\"search\" data-props=\"{}\"><input></coyote-island><coyote-island data-island=\"counter\" data-props=\"{&quot;count&quot;:2}\"><button>2</button></coyote-island><script type=\"application/json\" id=\"coyote-islands\">[\"counter\",\"search\"]</script></body>";
+
+    let mut html = Html::new();
+    let results = html.render(&template);
+
+    assert_eq!(Ok(expected.to_string()), results);
+}
+
+#[test]
+fn island_manifest_escapes_names() {
+    let template = document(island("</script>", "{}", Component::None));
+    let expected = "<body><coyote-island data-island=\"</script>\" data-props=\"{}\"></coyote-island><script type=\"application/json\" id=\"coyote-islands\">[\"\\u003c/script\\u003e\"]</script></body>";
+
+    let mut html = Html::new();
+    let results = html.render(&template);
+
+    assert_eq!(Ok(expected.to_string()), results);
+}
+
+#[test]
+fn island_manifest_is_empty_without_islands() {
+    let template = document(text("hai :3"));
+    let expected = "<body>hai :3</body>";
+
+    let mut html = Html::new();
+    let results = html.render(&template);
+
+    assert_eq!(Ok(expected.to_string()), results);
+}
+
+#[test]
+fn island_manifest_is_removed_by_html_only() {
+    let template = document(counter(1));
+    let expected = "<body><coyote-island data-island=\"counter\" data-props=\"{&quot;count&quot;:1}\"><button>1</button></coyote-island></body>";
+
+    let mut html = HtmlOnly::new();
+    let results = html.render(&template);
+
+    assert_eq!(Ok(expected.to_string()), results);
+}
+
+#[test]
+fn island_props_are_written_verbatim() {
+    let template = tmpl(
+        "<main>\n\t{}\n</main>",
+        [island(
+            "greeting",
+            "{\"msg\":\"hello    world\",\n\"n\": 1, \"html\": \"<b>&</b>\"}",
+            Component::None,
+        )],
+    );
+    let expected = "<main>\n\t<coyote-island data-island=\"greeting\" data-props=\"{&quot;msg&quot;:&quot;hello    world&quot;,\n&quot;n&quot;: 1, &quot;html&quot;: &quot;&lt;b>&amp;&lt;/b>&quot;}\"></coyote-island>\n</main>";
+
+    let mut html = Html::new();
+    let results = html.render(&template);
+
+    assert_eq!(Ok(expected.to_string()), results);
+}
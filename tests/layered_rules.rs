@@ -0,0 +1,215 @@
+use coyotes::{
+    Document, DuplicateAttrPolicy, ElementSet, Errors, HtmlRules, LayeredRules, XmlRules, attr_val,
+    text, tmpl,
+};
+
+#[test]
+fn layered_rules_delegate_to_base() {
+    let template = tmpl("<p>hai {}<br>:3</p>", [text("coyote")]);
+    let expected = "<p>hai coyote<br>:3</p>";
+
+    let mut document = Document::with_rules(LayeredRules::new(HtmlRules::new()));
+    let results = document.render(&template);
+
+    assert_eq!(Ok(expected.to_string()), results);
+}
+
+#[test]
+fn layered_rules_ban_extra_tags() {
+    let template = tmpl("<p>hai<iframe>coyote</iframe>:3</p>", []);
+    let expected = "<p>hai:3</p>";
+
+    let rules =
+        LayeredRules::new(HtmlRules::new()).with_banned(ElementSet::new().include("iframe"));
+
+    let mut document = Document::with_rules(rules);
+    let results = document.render(&template);
+
+    assert_eq!(Ok(expected.to_string()), results);
+}
+
+#[test]
+fn layered_rules_ban_base_tags() {
+    let template = tmpl("<p>hai<script>:3</script></p>", []);
+    let expected = "<p>hai<script>:3</script></p>";
+
+    let rules = LayeredRules::new(HtmlRules::new());
+    let mut document = Document::with_rules(rules);
+    assert_eq!(Ok(expected.to_string()), document.render(&template));
+
+    let rules =
+        LayeredRules::new(HtmlRules::new()).with_banned(ElementSet::new().include("script"));
+    let mut document = Document::with_rules(rules);
+    assert_eq!(Ok("<p>hai</p>".to_string()), document.render(&template));
+}
+
+#[test]
+fn layered_rules_add_void_tags() {
+    let template = tmpl("<p>hai <coyote-icon> :3</p>", []);
+    let expected = "<p>hai <coyote-icon> :3</p>";
+
+    let rules =
+        LayeredRules::new(HtmlRules::new()).with_void(ElementSet::new().include("coyote-icon"));
+
+    let mut document = Document::with_rules(rules);
+    let results = document.render(&template);
+
+    assert_eq!(Ok(expected.to_string()), results);
+}
+
+#[test]
+fn layered_rules_add_raw_text_tags() {
+    let template = tmpl("<x-code><p>{}</p></x-code>", []);
+    let expected = "<x-code><p>{}</p></x-code>";
+
+    let rules =
+        LayeredRules::new(HtmlRules::new()).with_raw_text(ElementSet::new().include("x-code"));
+
+    let mut document = Document::with_rules(rules);
+    let results = document.render(&template);
+
+    assert_eq!(Ok(expected.to_string()), results);
+}
+
+#[test]
+fn layered_rules_remove_raw_text_tags() {
+    let template = tmpl("<style><p>{}</p></style>", [text("coyote")]);
+    let expected = "<style><p>coyote</p></style>";
+
+    let rules =
+        LayeredRules::new(HtmlRules::new()).with_raw_text(ElementSet::new().exclude("style"));
+
+    let mut document = Document::with_rules(rules);
+    let results = document.render(&template);
+
+    assert_eq!(Ok(expected.to_string()), results);
+}
+
+#[test]
+fn layered_rules_layer_over_layered_rules() {
+    let template = tmpl("<x-code>a < b</x-code><coyote-icon>", []);
+    let expected = "<x-code>a < b</x-code><coyote-icon>";
+
+    let rules = LayeredRules::new(
+        LayeredRules::new(XmlRules::new()).with_raw_text(ElementSet::new().include("x-code")),
+    )
+    .with_void(ElementSet::new().include("coyote-icon"));
+
+    let mut document = Document::with_rules(rules);
+    let results = document.render(&template);
+
+    assert_eq!(Ok(expected.to_string()), results);
+}
+
+#[test]
+fn layered_rules_apply_tag_rules() {
+    let template = tmpl("<p>hai<x-secret-note>coyote</x-secret-note> :3</p>", []);
+    let expected = "<p>hai :3</p>";
+
+    let rules = LayeredRules::new(HtmlRules::new())
+        .with_banned_rule(|tag, banned| banned || tag.starts_with("x-secret"));
+
+    let mut document = Document::with_rules(rules);
+    let results = document.render(&template);
+
+    assert_eq!(Ok(expected.to_string()), results);
+}
+
+#[test]
+fn layered_rules_apply_rules_after_sets() {
+    let template = tmpl("<p>hai<iframe>coyote</iframe>:3</p>", []);
+    let expected = "<p>hai<iframe>coyote</iframe>:3</p>";
+
+    let rules = LayeredRules::new(HtmlRules::new())
+        .with_banned(ElementSet::new().include("iframe"))
+        .with_banned_rule(|tag, banned| banned && tag != "iframe");
+
+    let mut document = Document::with_rules(rules);
+    let results = document.render(&template);
+
+    assert_eq!(Ok(expected.to_string()), results);
+}
+
+#[test]
+fn layered_rules_override_close_sequences() {
+    let template = tmpl("<x-raw><p>{}</p></x-raw><style>{}</style>", []);
+    let expected = "<x-raw><p>{}</p></x-raw><style>{}</style>";
+
+    let rules = LayeredRules::new(HtmlRules::new()).with_close_sequence("x-raw", "</x-raw");
+
+    let mut document = Document::with_rules(rules);
+    let results = document.render(&template);
+
+    assert_eq!(Ok(expected.to_string()), results);
+}
+
+#[test]
+fn layered_rules_override_duplicate_attr_policy() {
+    let template = tmpl("<p id=\"a\" {}>hai :3</p>", [attr_val("id", "b")]);
+    let expected = Errors::DuplicateAttribute("id".to_string(), "p".to_string());
+
+    let rules = LayeredRules::new(HtmlRules::new())
+        .with_duplicate_attr_policy(|_| DuplicateAttrPolicy::Error);
+
+    let mut document = Document::with_rules(rules);
+    let results = document.render(&template);
+
+    assert_eq!(Err(expected), results);
+}
+
+#[test]
+fn layered_rules_override_mergeable_attrs() {
+    let template = tmpl(
+        "<a rel=\"noopener\" {}>hai :3</a>",
+        [attr_val("rel", "external")],
+    );
+    let expected = "<a rel=\"noopener external\">hai :3</a>";
+
+    let rules = LayeredRules::new(HtmlRules::new())
+        .with_mergeable_attr_rule(|attr, mergeable| mergeable || attr == "rel");
+
+    let mut document = Document::with_rules(rules);
+    let results = document.render(&template);
+
+    assert_eq!(Ok(expected.to_string()), results);
+}
+
+#[test]
+fn layered_rules_override_comments_and_indentation() {
+    let template = tmpl(
+        "<ul>\n\t<!-- items -->\n\t<li>{}</li>\n</ul>",
+        [text("hai")],
+    );
+    let expected = "<ul>\n<li>hai</li>\n</ul>";
+
+    let rules = LayeredRules::new(HtmlRules::new())
+        .with_keep_comments(|_| false)
+        .with_respect_indentation(|_| false);
+
+    let mut document = Document::with_rules(rules);
+    let results = document.render(&template);
+
+    assert_eq!(Ok(expected.to_string()), results);
+}
+
+#[test]
+fn layered_rules_override_initial_embedded_content() {
+    let template = tmpl("<coyote/>", []);
+    let expected = "<coyote/>";
+
+    let rules = LayeredRules::new(HtmlRules::new()).with_initial_embedded_content("xml");
+
+    let mut document = Document::with_rules(rules);
+    let results = document.render(&template);
+
+    assert_eq!(Ok(expected.to_string()), results);
+}
+
+#[test]
+fn layered_rules_are_send_and_sync() {
+    fn is_send_and_sync<T: Send + Sync>(_: &T) {}
+
+    let rules = LayeredRules::new(HtmlRules::new()).with_inline_rule(|_, inline| inline);
+
+    is_send_and_sync(&rules);
+}
@@ -0,0 +1,95 @@
+use coyotes::{
+    Component, Document, DocumentParams, Html, HtmlRules, RulesetImpl, TemplateBuilderImpl,
+    TemplateSteps, compose, memo, text, tmpl,
+};
+
+// a template builder without a memo cache
+struct StepsBuilder {}
+
+impl TemplateBuilderImpl for StepsBuilder {
+    fn build(&mut self, rules: &dyn RulesetImpl, template_str: &str) -> TemplateSteps {
+        compose(rules, template_str)
+    }
+}
+
+fn menu(label: &str) -> Component {
+    tmpl(
+        "
+        <ul>
+            <li>{}</li>
+        </ul>
+        ",
+        [text(label)],
+    )
+}
+
+fn page(content: Component) -> Component {
+    tmpl(
+        "
+        <body>
+            {}
+        </body>
+        ",
+        [content],
+    )
+}
+
+#[test]
+fn memo_renders_like_component() {
+    let template = page(memo("menu", menu("home")));
+    let expected = "<body>\n\t<ul>\n\t\t<li>home</li>\n\t</ul>\n</body>";
+
+    let mut html = Html::new();
+    let results = html.render(&template);
+
+    assert_eq!(Ok(expected.to_string()), results);
+}
+
+#[test]
+fn memo_splices_cached_results() {
+    let expected = "<body>\n\t<ul>\n\t\t<li>home</li>\n\t</ul>\n</body>";
+
+    let mut html = Html::new();
+    let _ = html.render(&page(memo("menu", menu("home"))));
+    let results = html.render(&page(memo("menu", menu("about"))));
+
+    assert_eq!(Ok(expected.to_string()), results);
+}
+
+#[test]
+fn memo_is_scoped_to_indentation() {
+    let expected = "<ul>\n\t<li>home</li>\n</ul>";
+
+    let mut html = Html::new();
+    let _ = html.render(&page(memo("menu", menu("home"))));
+    let results = html.render(&memo("menu", menu("home")));
+
+    assert_eq!(Ok(expected.to_string()), results);
+}
+
+#[test]
+fn memo_cache_obeys_cache_memory_limit() {
+    let params = DocumentParams {
+        cache_memory_limit: 0,
+        document_memory_limit: 1024,
+        ..Default::default()
+    };
+    let expected = "<body>\n\t<ul>\n\t\t<li>about</li>\n\t</ul>\n</body>";
+
+    let mut html = Html::from(&params);
+    let _ = html.render(&page(memo("menu", menu("home"))));
+    let results = html.render(&page(memo("menu", menu("about"))));
+
+    assert_eq!(Ok(expected.to_string()), results);
+}
+
+#[test]
+fn memo_renders_without_memo_cache() {
+    let expected = "<body>\n\t<ul>\n\t\t<li>about</li>\n\t</ul>\n</body>";
+
+    let mut document = Document::with_builder(HtmlRules::new(), StepsBuilder {});
+    let _ = document.render(&page(memo("menu", menu("home"))));
+    let results = document.render(&page(memo("menu", menu("about"))));
+
+    assert_eq!(Ok(expected.to_string()), results);
+}
@@ -0,0 +1,84 @@
+use coyotes::{Html, HtmlOnly, Xml, text, tmpl};
+
+#[test]
+fn textarea_keeps_spaces() {
+    let template = tmpl(
+        "
+        <form>
+            <textarea name=note>
+  keep   this
+    indented  </textarea>
+        </form>
+        ",
+        [],
+    );
+    let expected =
+        "<form>\n\t<textarea name=note>\n  keep   this\n    indented  </textarea>\n</form>";
+
+    let mut html = Html::new();
+    let results = html.render(&template);
+
+    assert_eq!(Ok(expected.to_string()), results);
+}
+
+#[test]
+fn textarea_treats_elements_as_text() {
+    let template = tmpl("<textarea><b>hai</b> :3</textarea>", []);
+    let expected = "<textarea><b>hai</b> :3</textarea>";
+
+    let mut html = Html::new();
+    let results = html.render(&template);
+
+    assert_eq!(Ok(expected.to_string()), results);
+}
+
+#[test]
+fn textarea_keeps_spaces_of_injections() {
+    let template = tmpl("<textarea>{}</textarea>", [text("  hai  <b>\n  coyote ")]);
+    let expected = "<textarea>  hai  &lt;b>\n  coyote </textarea>";
+
+    let mut html = Html::new();
+    let results = html.render(&template);
+
+    assert_eq!(Ok(expected.to_string()), results);
+}
+
+#[test]
+fn title_renders_injections() {
+    let template = tmpl(
+        "
+        <head>
+            <title>{} &amp; <friends></title>
+        </head>
+        ",
+        [text("coyote")],
+    );
+    let expected = "<head>\n\t<title>coyote &amp; <friends></title>\n</head>";
+
+    let mut html = Html::new();
+    let results = html.render(&template);
+
+    assert_eq!(Ok(expected.to_string()), results);
+}
+
+#[test]
+fn empty_rcdata_elements() {
+    let template = tmpl("<textarea></textarea><title>{}</title>", [text("hai")]);
+    let expected = "<textarea></textarea><title>hai</title>";
+
+    let mut html = HtmlOnly::new();
+    let results = html.render(&template);
+
+    assert_eq!(Ok(expected.to_string()), results);
+}
+
+#[test]
+fn xml_title_is_an_element() {
+    let template = tmpl("<title>  hai <b>coyote</b>  </title>", []);
+    let expected = "<title> hai <b>coyote</b> </title>";
+
+    let mut xml = Xml::new();
+    let results = xml.render(&template);
+
+    assert_eq!(Ok(expected.to_string()), results);
+}
@@ -0,0 +1,119 @@
+use coyotes::{
+    Component, Html, RenderContext, Xml, attr_val, lazy, list, memo, provide, text, tmpl,
+};
+
+struct Locale(&'static str);
+struct Nonce(String);
+
+fn greeting() -> Component {
+    lazy(|context| match context.get::<Locale>() {
+        Some(Locale("fr")) => text("bonjour"),
+        Some(Locale("es")) => text("hola"),
+        _ => text("hello"),
+    })
+}
+
+fn script() -> Component {
+    tmpl(
+        "<script {}></script>",
+        [lazy(|context| match context.get::<Nonce>() {
+            Some(Nonce(nonce)) => attr_val("nonce", nonce),
+            _ => Component::None,
+        })],
+    )
+}
+
+#[test]
+fn lazy_components_render_without_context() {
+    let template = tmpl("<p>{}</p>", [greeting()]);
+    let expected = "<p>hello</p>";
+
+    let mut html = Html::new();
+    let results = html.render(&template);
+
+    assert_eq!(Ok(expected.to_string()), results);
+}
+
+#[test]
+fn lazy_components_read_render_context() {
+    let template = tmpl("<p>{}</p>", [greeting()]);
+    let expected = "<p>bonjour</p>";
+
+    let context = RenderContext::new().with(Locale("fr"));
+
+    let mut html = Html::new();
+    let results = html.render_with_context(&template, &context);
+
+    assert_eq!(Ok(expected.to_string()), results);
+}
+
+#[test]
+fn provided_context_is_scoped_to_descendants() {
+    let template = tmpl(
+        "<p>{}</p><p>{}</p><p>{}</p>",
+        [
+            greeting(),
+            provide(
+                RenderContext::new().with(Locale("es")),
+                list([text("¡"), greeting(), text("!")]),
+            ),
+            greeting(),
+        ],
+    );
+    let expected = "<p>bonjour</p><p>¡hola!</p><p>bonjour</p>";
+
+    let context = RenderContext::new().with(Locale("fr"));
+
+    let mut html = Html::new();
+    let results = html.render_with_context(&template, &context);
+
+    assert_eq!(Ok(expected.to_string()), results);
+}
+
+#[test]
+fn provided_context_extends_ancestor_context() {
+    let template = provide(
+        RenderContext::new().with(Nonce("uwu".to_string())),
+        tmpl("<p>{}</p>{}", [greeting(), script()]),
+    );
+    let expected = "<p>bonjour</p><script nonce=\"uwu\"></script>";
+
+    let context = RenderContext::new().with(Locale("fr"));
+
+    let mut xml = Xml::new();
+    let results = xml.render_with_context(&template, &context);
+
+    assert_eq!(Ok(expected.to_string()), results);
+}
+
+#[test]
+fn lazy_attributes_render_with_context() {
+    let expected = "<script></script><script nonce=\"abc123\"></script>";
+
+    let template = list([
+        script(),
+        provide(
+            RenderContext::new().with(Nonce("abc123".to_string())),
+            script(),
+        ),
+    ]);
+
+    let mut html = Html::new();
+    let results = html.render(&template);
+
+    assert_eq!(Ok(expected.to_string()), results);
+}
+
+#[test]
+fn memo_caches_lazy_results() {
+    let expected = "<p>hello</p>";
+
+    let mut html = Html::new();
+    let _ = html.render(&tmpl("<p>{}</p>", [memo("greeting", greeting())]));
+    let results = html.render_with_context(
+        &tmpl("<p>{}</p>", [memo("greeting", greeting())]),
+        &RenderContext::new().with(Locale("fr")),
+    );
+
+    assert_eq!(Ok(expected.to_string()), results);
+}
@@ -0,0 +1,86 @@
+use coyotes::{Document, Html, HtmlRules, RulesetImpl, attr_val, text, tmpl};
+
+// a ruleset that only implements required methods
+struct MinimalRules {
+    rules: HtmlRules,
+}
+
+impl RulesetImpl for MinimalRules {
+    fn get_document_memory_limit(&self) -> usize {
+        self.rules.get_document_memory_limit()
+    }
+
+    fn get_cache_memory_limit(&self) -> usize {
+        self.rules.get_cache_memory_limit()
+    }
+
+    fn get_alt_text_tag_from_close_sequence(&self, tag: &str) -> Option<&str> {
+        self.rules.get_alt_text_tag_from_close_sequence(tag)
+    }
+
+    fn get_close_sequence_from_alt_text_tag(&self, tag: &str) -> Option<&str> {
+        self.rules.get_close_sequence_from_alt_text_tag(tag)
+    }
+
+    fn get_close_sequence_from_contentless_tag(&self, tag: &str) -> Option<&str> {
+        self.rules.get_close_sequence_from_contentless_tag(tag)
+    }
+
+    fn get_contentless_tag_from_close_sequence(&self, tag: &str) -> Option<&str> {
+        self.rules.get_contentless_tag_from_close_sequence(tag)
+    }
+
+    fn get_initial_embedded_content(&self) -> &str {
+        self.rules.get_initial_embedded_content()
+    }
+
+    fn respect_indentation(&self) -> bool {
+        self.rules.respect_indentation()
+    }
+
+    fn tag_is_banned_el(&self, tag: &str) -> bool {
+        self.rules.tag_is_banned_el(tag)
+    }
+
+    fn tag_is_inline_el(&self, tag: &str) -> bool {
+        self.rules.tag_is_inline_el(tag)
+    }
+
+    fn tag_is_embedded_content_el(&self, tag: &str) -> bool {
+        self.rules.tag_is_embedded_content_el(tag)
+    }
+
+    fn tag_is_prefix_of_contentless_el(&self, tag: &str) -> Option<&str> {
+        self.rules.tag_is_prefix_of_contentless_el(tag)
+    }
+
+    fn tag_is_preformatted_text_el(&self, tag: &str) -> bool {
+        self.rules.tag_is_preformatted_text_el(tag)
+    }
+
+    fn tag_is_void_el(&self, tag: &str) -> bool {
+        self.rules.tag_is_void_el(tag)
+    }
+}
+
+#[test]
+fn default_methods_follow_html_rules() {
+    let template = tmpl(
+        "
+        <!-- coyote -->
+        <p class=\"a\" {}>hai :3</p>
+        <template><textarea>  {}  </textarea></template>
+        ",
+        [attr_val("class", "b"), text("<coyote>")],
+    );
+
+    let mut html = Html::new();
+    let expected = html.render(&template);
+
+    let mut document = Document::with_rules(MinimalRules {
+        rules: HtmlRules::new(),
+    });
+    let results = document.render(&template);
+
+    assert_eq!(expected, results);
+}
@@ -0,0 +1,166 @@
+use coyotes::{Component, Html, ScopedStyle, Xml, list, portal, scoped, slot, text, tmpl};
+
+fn card_style() -> ScopedStyle {
+    ScopedStyle::new(
+        "card",
+        ".card { color: red; } .card p:hover, h2::before { color: blue; }",
+    )
+}
+
+fn card(style: &ScopedStyle, title: &str) -> Component {
+    scoped(
+        style,
+        tmpl(
+            "<div class=\"card\"><!-- card --><h2>{}</h2></div>",
+            [text(title)],
+        ),
+    )
+}
+
+#[test]
+fn scoped_style_rewrites_selectors() {
+    let style = card_style();
+    let attr = style.get_attr();
+
+    assert!(attr.starts_with("data-card-"));
+    assert_eq!(
+        format!(
+            ".card[{attr}] {{ color: red; }} .card p[{attr}]:hover, h2[{attr}]::before {{ color: blue; }}"
+        ),
+        style.get_css()
+    );
+}
+
+#[test]
+fn scoped_style_rewrites_nested_rules() {
+    let style = ScopedStyle::new(
+        "list",
+        "/* items */@media (min-width: 32em) { ul > li { margin: 0; } } @keyframes fade { from { opacity: 0; } }",
+    );
+    let attr = style.get_attr();
+
+    assert_eq!(
+        format!(
+            "@media (min-width: 32em) {{ ul > li[{attr}] {{ margin: 0; }} }} @keyframes fade {{ from {{ opacity: 0; }} }}"
+        ),
+        style.get_css()
+    );
+}
+
+#[test]
+fn scope_attribute_is_stable() {
+    assert_eq!(card_style().get_attr(), card_style().get_attr());
+    assert_ne!(
+        card_style().get_attr(),
+        ScopedStyle::new("card", ".card { color: green; }").get_attr()
+    );
+}
+
+#[test]
+fn scoped_components_render_scope_attribute_and_styles_once() {
+    let style = card_style();
+    let attr = style.get_attr();
+
+    let template = tmpl(
+        "<head>{}</head><body>{}</body>",
+        [
+            slot("head"),
+            list([
+                card(&style, "coyote"),
+                tmpl("<p>{}</p>", [text("unscoped")]),
+                card(&style, "wolf"),
+            ]),
+        ],
+    );
+    let expected = format!(
+        "<head><style>{}</style></head><body><div {attr} class=\"card\"><!-- card --><h2 {attr}>coyote</h2></div><p>unscoped</p><div {attr} class=\"card\"><!-- card --><h2 {attr}>wolf</h2></div></body>",
+        style.get_css()
+    );
+
+    let mut html = Html::new();
+    let results = html.render(&template);
+
+    assert_eq!(Ok(expected), results);
+}
+
+#[test]
+fn nested_scopes_replace_outer_scopes() {
+    let outer = ScopedStyle::new("outer", "section { padding: 0; }");
+    let inner = card_style();
+
+    let template = scoped(
+        &outer,
+        tmpl("<section>{}</section>", [card(&inner, "coyote")]),
+    );
+    let expected = format!(
+        "<section {}><div {} class=\"card\"><!-- card --><h2 {}>coyote</h2></div></section>",
+        outer.get_attr(),
+        inner.get_attr(),
+        inner.get_attr()
+    );
+
+    let mut html = Html::new();
+    let results = html.render(&template);
+
+    assert_eq!(Ok(expected), results);
+}
+
+#[test]
+fn scoped_components_inside_portals_render_styles() {
+    let style = card_style();
+    let attr = style.get_attr();
+
+    let template = tmpl(
+        "<head>{}</head><body>{}</body>",
+        [
+            slot("head"),
+            list([
+                slot("modal"),
+                portal("modal", "card", card(&style, "coyote")),
+            ]),
+        ],
+    );
+    let expected = format!(
+        "<head><style>{}</style></head><body><div {attr} class=\"card\"><!-- card --><h2 {attr}>coyote</h2></div></body>",
+        style.get_css()
+    );
+
+    let mut html = Html::new();
+    let results = html.render(&template);
+
+    assert_eq!(Ok(expected), results);
+}
+
+#[test]
+fn injected_components_do_not_receive_scope_attribute() {
+    let style = card_style();
+    let attr = style.get_attr();
+
+    let template = scoped(
+        &style,
+        tmpl(
+            "<div class=\"card\">{}<h2>{}</h2></div>",
+            [tmpl("<p>child</p>", []), text("coyote")],
+        ),
+    );
+    let expected = format!("<div {attr} class=\"card\"><p>child</p><h2 {attr}>coyote</h2></div>");
+
+    let mut html = Html::new();
+    let results = html.render(&template);
+
+    assert_eq!(Ok(expected), results);
+}
+
+#[test]
+fn scope_attribute_has_empty_value_in_xml() {
+    let style = card_style();
+    let attr = style.get_attr();
+
+    let template = scoped(&style, tmpl("<a><b>{}</b></a>", [text("coyote")]));
+    let expected = format!("<a {attr}=\"\"><b {attr}=\"\">coyote</b></a>");
+
+    let mut xml = Xml::new();
+    let results = xml.render(&template);
+
+    assert_eq!(Ok(expected), results);
+}
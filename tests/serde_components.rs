@@ -0,0 +1,76 @@
+#![cfg(feature = "serde")]
+
+use coyotes::{
+    Component, Html, Template, attr_url, attr_val, classes, list, memo, shared, text, tmpl,
+    tmpl_string,
+};
+use std::sync::Arc;
+
+fn form() -> Component {
+    let attributes = list([
+        attr_val("action", "/uwu"),
+        attr_url("formaction", "/uwu?q=good dog"),
+        classes(["form"]),
+    ]);
+    let descendants = list([
+        text("you're a good dog aren't you?"),
+        memo("submit", tmpl("<input type=submit value='yus ^_^'>", [])),
+    ]);
+
+    tmpl(
+        "
+        <form {}>
+            {}
+        </form>
+        ",
+        [attributes, descendants],
+    )
+}
+
+#[test]
+fn components_serialize_and_deserialize() {
+    let component = list([
+        form(),
+        shared(&Arc::new(tmpl_string("<p>{}</p>", [text("hai :3")]))),
+    ]);
+
+    let serialized = match serde_json::to_string(&component) {
+        Ok(serialized) => serialized,
+        Err(e) => panic!("{}", e),
+    };
+    let deserialized: Component = match serde_json::from_str(&serialized) {
+        Ok(deserialized) => deserialized,
+        Err(e) => panic!("{}", e),
+    };
+
+    let mut html = Html::new();
+    assert_eq!(html.render(&component), html.render(&deserialized));
+}
+
+#[test]
+fn templates_deserialize_into_template_strings() {
+    let serialized = "{\"Tmpl\":[{\"template_str\":\"<p>{}</p>\"},[{\"Text\":\"hai :3\"}]]}";
+    let expected = tmpl_string("<p>{}</p>", [text("hai :3")]);
+
+    let deserialized: Component = match serde_json::from_str(serialized) {
+        Ok(deserialized) => deserialized,
+        Err(e) => panic!("{}", e),
+    };
+
+    assert_eq!(expected, deserialized);
+}
+
+#[test]
+fn templates_deserialize_from_static_data() {
+    static SERIALIZED: &str = "{\"template_str\":\"<p>hai :3</p>\"}";
+    let expected = Template {
+        template_str: "<p>hai :3</p>",
+    };
+
+    let deserialized: Template = match serde_json::from_str(SERIALIZED) {
+        Ok(deserialized) => deserialized,
+        Err(e) => panic!("{}", e),
+    };
+
+    assert_eq!(expected, deserialized);
+}
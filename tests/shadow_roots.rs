@@ -0,0 +1,100 @@
+use coyotes::{
+    Component, Html, HtmlOnly, ScopedStyle, ShadowRootMode, el, scoped, shadow_root, text, tmpl,
+};
+
+fn profile_card(name: &str) -> Component {
+    tmpl(
+        "
+        <profile-card>
+            {}
+            <span slot=\"name\">{}</span>
+        </profile-card>
+        ",
+        [
+            shadow_root(
+                ShadowRootMode::Open,
+                ":host { display: block; }",
+                tmpl(
+                    "
+                    <article>
+                        <slot name=\"name\"></slot>
+                    </article>
+                    ",
+                    [],
+                ),
+            ),
+            text(name),
+        ],
+    )
+}
+
+#[test]
+fn shadow_root_renders_template_with_styles() {
+    let template = profile_card("coyote");
+    let expected = "<profile-card>\n\t<template shadowrootmode=\"open\"><style>:host { display: block; }</style>\n\t\t<article>\n\t\t\t<slot name=\"name\"></slot>\n\t\t</article>\n\t</template>\n\t<span slot=\"name\">coyote</span>\n</profile-card>";
+
+    let mut html = Html::new();
+    let results = html.render(&template);
+
+    assert_eq!(Ok(expected.to_string()), results);
+}
+
+#[test]
+fn shadow_root_without_styles() {
+    let template = el("count-down")
+        .child(shadow_root(
+            ShadowRootMode::Closed,
+            "",
+            tmpl("<b>{}</b>", [text("3")]),
+        ))
+        .build();
+    let expected =
+        "<count-down><template shadowrootmode=\"closed\"><b>3</b></template></count-down>";
+
+    let mut html = Html::new();
+    let results = html.render(&template);
+
+    assert_eq!(Ok(expected.to_string()), results);
+}
+
+#[test]
+fn shadow_root_content_is_not_scoped_by_ancestors() {
+    let style = ScopedStyle::new("page", "p { margin: 0; }");
+    let template = scoped(
+        &style,
+        tmpl(
+            "<x-el>{}<p>light</p></x-el>",
+            [shadow_root(
+                ShadowRootMode::Open,
+                "",
+                tmpl("<p>shadow</p>", []),
+            )],
+        ),
+    );
+    let expected = format!(
+        "<x-el {attr}><template shadowrootmode=\"open\"><p>shadow</p></template><p {attr}>light</p></x-el>",
+        attr = style.get_attr()
+    );
+
+    let mut html = Html::new();
+    let results = html.render(&template);
+
+    assert_eq!(Ok(expected), results);
+}
+
+#[test]
+fn shadow_root_content_obeys_banned_elements() {
+    let template = el("x-el")
+        .child(shadow_root(
+            ShadowRootMode::Open,
+            "p { color: red; }",
+            tmpl("<script>alert('uwu')</script><p>hai :3</p>", []),
+        ))
+        .build();
+    let expected = "<x-el><template shadowrootmode=\"open\"><p>hai :3</p></template></x-el>";
+
+    let mut html = HtmlOnly::new();
+    let results = html.render(&template);
+
+    assert_eq!(Ok(expected.to_string()), results);
+}
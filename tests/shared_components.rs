@@ -0,0 +1,79 @@
+use coyotes::{Component, Html, attr_val, list, shared, text, tmpl};
+use std::sync::Arc;
+use std::thread;
+
+fn nav() -> Component {
+    tmpl(
+        "
+        <nav>
+            <a href=\"/\">home</a>
+            <a href=\"/about\">about</a>
+        </nav>
+        ",
+        [],
+    )
+}
+
+fn page(nav: &Arc<Component>, content: Component) -> Component {
+    tmpl(
+        "
+        <body>
+            {}
+            <main>{}</main>
+        </body>
+        ",
+        [shared(nav), content],
+    )
+}
+
+#[test]
+fn shared_component_renders_like_owned_component() {
+    let nav_arc = Arc::new(nav());
+    let expected = "<body>\n\t<nav>\n\t\t<a href=\"/\">home</a>\n\t\t<a href=\"/about\">about</a>\n\t</nav>\n\t<main>hai :3</main>\n</body>";
+
+    let mut html = Html::new();
+    let results = html.render(&page(&nav_arc, text("hai :3")));
+
+    assert_eq!(Ok(expected.to_string()), results);
+}
+
+#[test]
+fn shared_attribute_injections() {
+    let attributes = Arc::new(list([
+        attr_val("action", "/uwu"),
+        attr_val("method", "post"),
+    ]));
+    let template = tmpl("<form {}></form>", [shared(&attributes)]);
+    let expected = "<form action=\"/uwu\" method=\"post\"></form>";
+
+    let mut html = Html::new();
+    let results = html.render(&template);
+
+    assert_eq!(Ok(expected.to_string()), results);
+}
+
+#[test]
+fn shared_component_renders_across_threads() {
+    let nav_arc = Arc::new(nav());
+    let expected = "<body>\n\t<nav>\n\t\t<a href=\"/\">home</a>\n\t\t<a href=\"/about\">about</a>\n\t</nav>\n\t<main>page 0</main>\n</body>";
+
+    let mut handles = Vec::new();
+    for index in 0..4 {
+        let nav_clone = nav_arc.clone();
+        handles.push(thread::spawn(move || {
+            let mut html = Html::new();
+            let content = text(&format!("page {}", index));
+            html.render(&page(&nav_clone, content))
+        }));
+    }
+
+    let mut results = Vec::new();
+    for handle in handles {
+        if let Ok(result) = handle.join() {
+            results.push(result);
+        }
+    }
+
+    assert_eq!(4, results.len());
+    assert_eq!(Ok(expected.to_string()), results[0]);
+}
@@ -0,0 +1,110 @@
+use coyotes::__private::TagInfo;
+use coyotes::{Component, Html, attr_val, list, text, tmpl};
+
+fn link(attrs: Component, label: &str) -> Component {
+    tmpl("<a class=\"link\" {}>{}</a>", [attrs, text(label)])
+}
+
+fn menu(items: Component) -> Component {
+    tmpl(
+        "
+        <ul>
+            <li>{}</li>
+        </ul>
+        ",
+        [items],
+    )
+}
+
+fn page(content: Component) -> Component {
+    tmpl(
+        "
+        <body>
+            {}
+        </body>
+        ",
+        [content],
+    )
+}
+
+#[test]
+fn cached_chunks_render_like_composed_chunks() {
+    let template = page(menu(text("home")));
+    let expected = "<body>\n\t<ul>\n\t\t<li>home</li>\n\t</ul>\n</body>";
+
+    let mut html = Html::new();
+    let results = html.render(&template);
+    assert_eq!(Ok(expected.to_string()), results);
+
+    let results = html.render(&template);
+    assert_eq!(Ok(expected.to_string()), results);
+}
+
+#[test]
+fn cached_chunks_respect_indentation_context() {
+    let nested = page(menu(text("home")));
+    let shallow = menu(text("home"));
+    let expected = "<ul>\n\t<li>home</li>\n</ul>";
+
+    let mut html = Html::new();
+    let _ = html.render(&nested);
+    let results = html.render(&shallow);
+
+    assert_eq!(Ok(expected.to_string()), results);
+}
+
+#[test]
+fn cached_chunks_move_attribute_positions() {
+    let first = list([text("a"), link(attr_val("class", "first"), "first")]);
+    let second = list([text("the"), link(attr_val("class", "second"), "second")]);
+
+    let mut html = Html::new();
+    let _ = html.render(&first);
+    let results = html.render(&second);
+
+    assert_eq!(Html::new().render(&second), results);
+
+    let expected = "the<a class=\"link second\">second</a>";
+
+    assert_eq!(Ok(expected.to_string()), results);
+}
+
+#[test]
+fn cached_chunks_render_across_documents() {
+    let links = list([
+        link(attr_val("class", "home"), "home"),
+        link(attr_val("class", "about"), "about"),
+    ]);
+    let template = page(menu(links));
+
+    let expected = Html::new().render(&template);
+
+    let mut html = Html::new();
+    let _ = html.render(&page(menu(text("home"))));
+    let results = html.render(&template);
+
+    assert_eq!(expected, results);
+}
+
+#[test]
+fn cached_chunks_render_within_other_ancestors() {
+    let nested = page(menu(text("home")));
+    let wrapped = tmpl("<main>{}</main>", [page(menu(text("home")))]);
+
+    let mut html = Html::new();
+    let _ = html.render(&nested);
+    let results = html.render(&wrapped);
+
+    assert_eq!(Html::new().render(&wrapped), results);
+}
+
+#[test]
+fn cached_chunks_count_towards_memory_footprint() {
+    let template = page(menu(text("home")));
+
+    let mut html = Html::new();
+    let results = html.render(&template).unwrap();
+    let footprint = html.get_builder().get_memory_footprint();
+
+    assert!(results.len() + std::mem::size_of::<TagInfo>() < footprint);
+}
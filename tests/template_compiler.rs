@@ -0,0 +1,124 @@
+use coyotes::{
+    CompileError, Errors, Html, HtmlRules, TemplateSteps, compile_templates, compose,
+    get_template_module, text,
+};
+use std::fs;
+use std::path::PathBuf;
+use std::process;
+
+fn create_template_dir(name: &str) -> PathBuf {
+    let dir = std::env::temp_dir().join(format!("coyotes-compiler-{}-{}", name, process::id()));
+    let _ = fs::remove_dir_all(&dir);
+
+    fs::create_dir_all(dir.join("partials")).unwrap();
+    fs::write(dir.join("page.html"), "<main {}>{}</main>").unwrap();
+    fs::write(dir.join("partials/nav-bar.html"), "<nav>{}</nav>").unwrap();
+    fs::write(dir.join("notes.txt"), "not a template").unwrap();
+
+    dir
+}
+
+#[test]
+fn compiler_generates_function_components() {
+    let dir = create_template_dir("functions");
+    let rules = HtmlRules::new();
+
+    let module = get_template_module(&rules, &dir).unwrap();
+
+    assert!(module.contains("pub const PAGE_TEMPLATE: &str = \"<main {}>{}</main>\";"));
+    assert!(module.contains(
+        "pub fn page(attrs_0: coyotes::Component, descendants_1: coyotes::Component) -> coyotes::Component {\n    coyotes::tmpl(PAGE_TEMPLATE, [attrs_0, descendants_1])\n}"
+    ));
+    assert!(module.contains(
+        "pub fn partials_nav_bar(descendants_0: coyotes::Component) -> coyotes::Component {"
+    ));
+    assert!(module.contains("pub fn get_template_steps()"));
+    assert!(!module.contains("notes"));
+
+    let _ = fs::remove_dir_all(&dir);
+}
+
+#[test]
+fn compiler_generates_template_steps() {
+    let dir = create_template_dir("steps");
+    let rules = HtmlRules::new();
+
+    let module = get_template_module(&rules, &dir).unwrap();
+    let steps = compose(&rules, "<nav>{}</nav>");
+
+    for step in steps.steps.iter().flatten().chain(steps.injs.iter()) {
+        let step_str = format!(
+            "coyotes::__private::Step {{ kind: coyotes::__private::StepKind::{:?}, origin: {}, target: {} }},",
+            step.kind, step.origin, step.target
+        );
+        assert!(module.contains(&step_str));
+    }
+
+    let _ = fs::remove_dir_all(&dir);
+}
+
+#[test]
+fn compiler_fails_on_unbalanced_templates() {
+    let dir = create_template_dir("unbalanced");
+    fs::write(dir.join("broken.html"), "<main><p>{}</main>").unwrap();
+
+    let results = get_template_module(&HtmlRules::new(), &dir);
+    let expected = Errors::UnbalancedTemplate("<main><p>{}</main>".to_string());
+
+    match results {
+        Err(CompileError::InvalidTemplate(name, e)) => {
+            assert_eq!("broken", name);
+            assert_eq!(expected, e);
+        }
+        _ => panic!("expected an invalid template"),
+    }
+
+    let _ = fs::remove_dir_all(&dir);
+}
+
+#[test]
+fn compiler_fails_on_duplicate_function_names() {
+    let dir = create_template_dir("duplicates");
+    fs::write(dir.join("partials/nav_bar.html"), "<nav></nav>").unwrap();
+
+    let results = get_template_module(&HtmlRules::new(), &dir);
+
+    match results {
+        Err(CompileError::DuplicateName(_, fn_name)) => assert_eq!("partials_nav_bar", fn_name),
+        _ => panic!("expected a duplicate name"),
+    }
+
+    let _ = fs::remove_dir_all(&dir);
+}
+
+#[test]
+fn compiler_writes_modules() {
+    let dir = create_template_dir("write");
+    let out_path = dir.join("templates.rs");
+
+    compile_templates(&HtmlRules::new(), &dir, &out_path).unwrap();
+    let module = fs::read_to_string(&out_path).unwrap();
+
+    assert_eq!(
+        get_template_module(&HtmlRules::new(), &dir).unwrap(),
+        module
+    );
+
+    let _ = fs::remove_dir_all(&dir);
+}
+
+#[test]
+fn prepared_steps_render() {
+    let template_str = "<nav>{}</nav>";
+    let rules = HtmlRules::new();
+    let steps: Vec<(&str, TemplateSteps)> =
+        Vec::from([(template_str, compose(&rules, template_str))]);
+
+    let mut html = Html::new();
+    html.prepare_steps(&steps);
+
+    let template = coyotes::tmpl(template_str, [text("hai :3")]);
+    let results = html.render(&template);
+
+    assert_eq!(Ok("<nav>hai :3</nav>".to_string()), results);
+}
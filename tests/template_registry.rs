@@ -0,0 +1,135 @@
+use coyotes::{Html, TemplateRegistry, text};
+use std::fs;
+use std::path::PathBuf;
+use std::process;
+use std::time::{Duration, SystemTime};
+
+fn create_template_dir(name: &str) -> PathBuf {
+    let dir = std::env::temp_dir().join(format!("coyotes-{}-{}", name, process::id()));
+    let _ = fs::remove_dir_all(&dir);
+
+    fs::create_dir_all(dir.join("partials")).unwrap();
+    fs::write(dir.join("page.html"), "<main>{}</main>").unwrap();
+    fs::write(dir.join("partials/greeting.html"), "<p>hai {} :3</p>").unwrap();
+    fs::write(dir.join("notes.txt"), "not a template").unwrap();
+
+    dir
+}
+
+// file systems with coarse timestamps need an explicit modified time
+fn write_template(path: &PathBuf, template_str: &str) {
+    fs::write(path, template_str).unwrap();
+
+    let file = fs::File::options().write(true).open(path).unwrap();
+    file.set_modified(SystemTime::now() + Duration::from_secs(10))
+        .unwrap();
+}
+
+#[test]
+fn registry_loads_templates_by_name() {
+    let dir = create_template_dir("load");
+    let registry = TemplateRegistry::load(&dir).unwrap();
+
+    assert_eq!(
+        Vec::from(["page".to_string(), "partials/greeting".to_string()]),
+        registry.get_names()
+    );
+    assert_eq!(None, registry.get("notes", []));
+
+    let _ = fs::remove_dir_all(&dir);
+}
+
+#[test]
+fn registry_templates_render() {
+    let dir = create_template_dir("render");
+    let registry = TemplateRegistry::load(&dir).unwrap();
+
+    let greeting = registry.get("partials/greeting", [text("coyote")]).unwrap();
+    let template = registry.get("page", [greeting]).unwrap();
+    let expected = "<main><p>hai coyote :3</p></main>";
+
+    let mut html = Html::new();
+    html.prepare(&registry);
+    let results = html.render(&template);
+
+    assert_eq!(Ok(expected.to_string()), results);
+
+    let _ = fs::remove_dir_all(&dir);
+}
+
+#[test]
+fn registry_reloads_modified_templates_in_dev_mode() {
+    let dir = create_template_dir("dev-mode");
+    let registry = TemplateRegistry::load(&dir).unwrap().with_dev_mode(true);
+
+    write_template(&dir.join("page.html"), "<article>{}</article>");
+
+    let template = registry.get("page", [text("hai :3")]).unwrap();
+    let expected = "<article>hai :3</article>";
+
+    let mut html = Html::new();
+    let results = html.render(&template);
+
+    assert_eq!(Ok(expected.to_string()), results);
+
+    let _ = fs::remove_dir_all(&dir);
+}
+
+#[test]
+fn registry_keeps_templates_outside_of_dev_mode() {
+    let dir = create_template_dir("production");
+    let registry = TemplateRegistry::load(&dir).unwrap();
+
+    write_template(&dir.join("page.html"), "<article>{}</article>");
+
+    assert_eq!(
+        Some("<main>{}</main>".to_string()),
+        registry.get_template_str("page")
+    );
+
+    let _ = fs::remove_dir_all(&dir);
+}
+
+#[test]
+fn registry_refresh_reads_new_and_modified_templates() {
+    let dir = create_template_dir("refresh");
+    let registry = TemplateRegistry::load(&dir).unwrap();
+
+    write_template(&dir.join("page.html"), "<article>{}</article>");
+    fs::write(dir.join("partials/footer.html"), "<footer></footer>").unwrap();
+    fs::remove_file(dir.join("partials/greeting.html")).unwrap();
+
+    assert_eq!(
+        Vec::from(["page".to_string(), "partials/footer".to_string()]),
+        registry.refresh().unwrap()
+    );
+    assert_eq!(
+        Vec::from(["page".to_string(), "partials/footer".to_string()]),
+        registry.get_names()
+    );
+
+    let _ = fs::remove_dir_all(&dir);
+}
+
+#[test]
+fn registry_finds_new_templates_in_dev_mode() {
+    let dir = create_template_dir("dev-mode-new");
+    let registry = TemplateRegistry::load(&dir).unwrap().with_dev_mode(true);
+
+    fs::write(dir.join("partials/footer.html"), "<footer></footer>").unwrap();
+
+    assert_eq!(
+        Some("<footer></footer>".to_string()),
+        registry.get_template_str("partials/footer")
+    );
+    assert_eq!(
+        Vec::from([
+            "page".to_string(),
+            "partials/footer".to_string(),
+            "partials/greeting".to_string()
+        ]),
+        registry.get_names()
+    );
+
+    let _ = fs::remove_dir_all(&dir);
+}
@@ -0,0 +1,70 @@
+use coyotes::{Html, attr_bool, attr_num, attr_opt, attr_url, list, tmpl};
+
+#[test]
+fn boolean_attributes_render_only_when_true() {
+    let template = tmpl(
+        "<button {}></button><button {}></button>",
+        [attr_bool("disabled", true), attr_bool("disabled", false)],
+    );
+    let expected = "<button disabled></button><button></button>";
+
+    let mut html = Html::new();
+    let results = html.render(&template);
+
+    assert_eq!(Ok(expected.to_string()), results);
+}
+
+#[test]
+fn numeric_attributes_format_consistently() {
+    let template = tmpl(
+        "<input {}>",
+        [list([
+            attr_num("min", -0.0),
+            attr_num("max", 10.0),
+            attr_num("step", 0.25_f32),
+            attr_num("size", 32_u8),
+            attr_num("value", f64::NAN),
+        ])],
+    );
+    let expected = "<input min=\"0\" max=\"10\" step=\"0.25\" size=\"32\">";
+
+    let mut html = Html::new();
+    let results = html.render(&template);
+
+    assert_eq!(Ok(expected.to_string()), results);
+}
+
+#[test]
+fn optional_attributes_are_omitted_when_none() {
+    let title: Option<&str> = None;
+    let template = tmpl(
+        "<img {}>",
+        [list([
+            attr_opt("alt", Some("a coyote")),
+            attr_opt("title", title),
+        ])],
+    );
+    let expected = "<img alt=\"a coyote\">";
+
+    let mut html = Html::new();
+    let results = html.render(&template);
+
+    assert_eq!(Ok(expected.to_string()), results);
+}
+
+#[test]
+fn url_attributes_are_percent_encoded() {
+    let template = tmpl(
+        "<a {}></a>",
+        [attr_url(
+            "href",
+            "/search?q=coyote howl&lang=fr\"><script>&emoji=🐺&ok=%20",
+        )],
+    );
+    let expected = "<a href=\"/search?q=coyote%20howl&lang=fr%22%3E%3Cscript%3E&emoji=%F0%9F%90%BA&ok=%20\"></a>";
+
+    let mut html = Html::new();
+    let results = html.render(&template);
+
+    assert_eq!(Ok(expected.to_string()), results);
+}